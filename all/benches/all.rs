@@ -0,0 +1,27 @@
+//! Benchmarks every registered day on its input, the same way as each day's own benchmarks.
+//! Inputs are found like the `all` runner does, see [`input::Inputs`]
+
+use aoc::bench::criterion::{criterion_group, criterion_main, Criterion};
+
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+#[path = "../src/input.rs"]
+#[allow(dead_code)]
+mod input;
+
+fn all(c: &mut Criterion) {
+    let inputs = input::Inputs::from_env();
+    for day in aoc::days() {
+        match inputs.load(day.name) {
+            Ok(input) => aoc::bench::bench_day(c, day, input),
+            Err((path, err)) => eprintln!(
+                "skipping {}: could not read {}: {err}",
+                day.name,
+                path.display()
+            ),
+        }
+    }
+}
+
+criterion_group!(benches, all);
+criterion_main!(benches);
@@ -0,0 +1,28 @@
+//! Writes a `use dayNN as _;` for every day in the dependencies, so that the runner and
+//! the benchmark link them all in without listing them again
+
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.toml");
+
+    let manifest = fs::read_to_string("Cargo.toml").expect("could not read Cargo.toml");
+    let manifest: toml::Table = manifest.parse().expect("Cargo.toml is not valid toml");
+    let mut days: Vec<&String> = manifest["dependencies"]
+        .as_table()
+        .expect("Cargo.toml has dependencies")
+        .keys()
+        .filter(|name| {
+            let number = name.strip_prefix("day").unwrap_or_default();
+            number.len() == 2 && number.bytes().all(|b| b.is_ascii_digit())
+        })
+        .collect();
+    days.sort();
+
+    let links: String = days
+        .iter()
+        .map(|day| format!("use {day} as _;\n"))
+        .collect();
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("days.rs");
+    fs::write(out, links).expect("could not write the day links");
+}
@@ -0,0 +1,206 @@
+//! Counts the allocations made by each step of a day.
+//!
+//! The counting allocator is only installed with the `count-allocs` feature,
+//! since it slows down every allocation a little
+
+use std::{cell::Cell, fmt};
+
+#[cfg(feature = "count-allocs")]
+use std::alloc::{GlobalAlloc, Layout, System};
+
+/// Whether the counting allocator was installed
+pub const ENABLED: bool = cfg!(feature = "count-allocs");
+
+/// Wraps the system allocator, counting the allocations made on each thread
+#[cfg(feature = "count-allocs")]
+pub struct Counting;
+
+#[derive(Clone, Copy)]
+struct Counters {
+    count: u64,
+    bytes: u64,
+    /// live bytes can go negative if memory is freed on a different thread
+    live: i64,
+    peak: i64,
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            count: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+fn update(f: impl FnOnce(&mut Counters)) {
+    // the counters are gone while the thread is being torn down
+    let _ = COUNTERS.try_with(|counters| {
+        let mut c = counters.get();
+        f(&mut c);
+        counters.set(c);
+    });
+}
+
+#[cfg(feature = "count-allocs")]
+fn allocated(size: usize) {
+    update(|c| {
+        c.count += 1;
+        c.bytes += size as u64;
+        c.live += size as i64;
+        c.peak = c.peak.max(c.live);
+    });
+}
+
+#[cfg(feature = "count-allocs")]
+fn freed(size: usize) {
+    update(|c| c.live -= size as i64);
+}
+
+#[cfg(feature = "count-allocs")]
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        freed(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new
+    }
+}
+
+/// The allocations made by a step. Reallocations count as a new allocation
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct Allocs {
+    pub count: u64,
+    pub bytes: u64,
+    /// the most memory that was allocated at once during the step
+    pub peak: u64,
+}
+
+/// Counts the allocations made on this thread while running `f`
+pub fn counted<T>(f: impl FnOnce() -> T) -> (T, Allocs) {
+    let mut before = None;
+    update(|c| {
+        c.peak = c.live;
+        before = Some(*c);
+    });
+    let t = f();
+    let mut allocs = Allocs::default();
+    if let Some(before) = before {
+        update(|c| {
+            allocs = Allocs {
+                count: c.count - before.count,
+                bytes: c.bytes - before.bytes,
+                peak: (c.peak - before.live).max(0) as u64,
+            }
+        });
+    }
+    (t, allocs)
+}
+
+/// Formats a number of bytes with a binary unit, eg `1.5 KiB`
+pub struct Bytes(pub u64);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut size = self.0 as f64;
+        for unit in ["B", "KiB", "MiB"] {
+            if size < 1024.0 {
+                return match unit {
+                    "B" => write!(f, "{} B", self.0),
+                    _ => write!(f, "{size:.1} {unit}"),
+                };
+            }
+            size /= 1024.0;
+        }
+        write!(f, "{size:.1} GiB")
+    }
+}
+
+impl fmt::Display for Allocs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} (peak {})",
+            self.count,
+            Bytes(self.bytes),
+            Bytes(self.peak)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{counted, Allocs, Bytes};
+
+    #[test]
+    fn bytes() {
+        assert_eq!(Bytes(0).to_string(), "0 B");
+        assert_eq!(Bytes(1023).to_string(), "1023 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(Bytes(3 << 20).to_string(), "3.0 MiB");
+        assert_eq!(Bytes(5 << 40).to_string(), "5120.0 GiB");
+    }
+
+    #[test]
+    fn allocs() {
+        let allocs = Allocs {
+            count: 2,
+            bytes: 2048,
+            peak: 100,
+        };
+        assert_eq!(allocs.to_string(), "2 allocs, 2.0 KiB (peak 100 B)");
+    }
+
+    #[test]
+    #[cfg(feature = "count-allocs")]
+    fn counted_allocations() {
+        let (len, allocs) = counted(|| {
+            let kept = Vec::<u8>::with_capacity(100);
+            drop(Vec::<u8>::with_capacity(400));
+            kept.capacity()
+        });
+        assert_eq!(len, 100);
+        assert_eq!(allocs.count, 2);
+        assert_eq!(allocs.bytes, 500);
+        assert_eq!(allocs.peak, 500);
+
+        // the peak is measured from what was live before the step
+        let kept = Vec::<u8>::with_capacity(1000);
+        let (_, allocs) = counted(|| drop(Vec::<u8>::with_capacity(10)));
+        assert_eq!(allocs.peak, 10);
+        drop(kept);
+    }
+
+    #[test]
+    #[cfg(not(feature = "count-allocs"))]
+    fn counted_without_the_allocator() {
+        let (len, allocs) = counted(|| vec![0u8; 100].len());
+        assert_eq!(len, 100);
+        assert_eq!(allocs, Allocs::default());
+    }
+}
@@ -0,0 +1,213 @@
+use std::{
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+use aoc::AocError;
+use serde::{Deserialize, Deserializer};
+
+use crate::runner::{Answer, Answers};
+
+/// Known-good answers for a day's own input, read from `challenges/<day>/answers.toml`
+///
+/// ```toml
+/// part_one = 24000
+/// part_two = "RLEZFLGE"
+/// ```
+#[derive(Deserialize, Default)]
+pub struct Expected {
+    #[serde(default, deserialize_with = "answer")]
+    pub part_one: Option<String>,
+    #[serde(default, deserialize_with = "answer")]
+    pub part_two: Option<String>,
+}
+
+/// Answers can be written as toml integers or strings
+fn answer<'de, D: Deserializer<'de>>(d: D) -> Result<Option<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Answer {
+        Int(i64),
+        Str(String),
+    }
+    Ok(match Answer::deserialize(d)? {
+        Answer::Int(i) => Some(i.to_string()),
+        Answer::Str(s) => Some(s),
+    })
+}
+
+impl Expected {
+    pub fn path(day: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../challenges")
+            .join(day)
+            .join("answers.toml")
+    }
+
+    /// Reads the expected answers. A missing file means there are no expected answers
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(s) => toml::from_str(&s).map_err(|err| format!("{}: {err}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("{}: {err}", path.display())),
+        }
+    }
+}
+
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    Error(String),
+    /// there is no known answer to compare against
+    Unknown,
+    /// the part was not run
+    Skipped,
+}
+
+impl Verdict {
+    fn new(expected: &Option<String>, answer: &Option<Answer>) -> Self {
+        match (expected, answer) {
+            (_, None) => Verdict::Skipped,
+            (_, Some(Err(err))) => Verdict::Error(err.to_string()),
+            (None, Some(Ok(_))) => Verdict::Unknown,
+            (Some(expected), Some(Ok(actual))) if expected == actual => Verdict::Pass,
+            (Some(expected), Some(Ok(actual))) => Verdict::Fail {
+                expected: expected.clone(),
+                actual: actual.clone(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => f.pad("pass"),
+            Verdict::Fail { .. } => f.pad("FAIL"),
+            Verdict::Error(_) => f.pad("ERROR"),
+            Verdict::Unknown => f.pad("?"),
+            Verdict::Skipped => f.pad("-"),
+        }
+    }
+}
+
+/// Compares both parts of a report's answers against the expected answers.
+/// A parse error shows where parsing failed in `input`
+pub fn verify(
+    answers: &Result<Answers, AocError>,
+    input: &str,
+    expected: &Result<Expected, String>,
+) -> [Verdict; 2] {
+    match (answers, expected) {
+        (Err(err), _) => {
+            let err = format!("failed to parse: {}", err.report(input).trim_end());
+            [Verdict::Error(err.clone()), Verdict::Error(err)]
+        }
+        (_, Err(err)) => [Verdict::Error(err.clone()), Verdict::Error(err.clone())],
+        (Ok(answers), Ok(expected)) => [
+            Verdict::new(&expected.part_one, &answers.part_one),
+            Verdict::new(&expected.part_two, &answers.part_two),
+        ],
+    }
+}
+
+/// Prints a pass/fail table for the reports, followed by the details of any failures.
+/// Returns the number of failures
+pub fn print_table(results: &[(&'static str, [Verdict; 2])]) -> usize {
+    println!("{:<8}{:<10}part two", "day", "part one");
+    for (day, [one, two]) in results {
+        println!("{day:<8}{one:<10}{two}");
+    }
+
+    let mut failures = 0;
+    for (day, verdicts) in results {
+        // a parse failure fails both parts the same way, so it is only shown once
+        if let [Verdict::Error(one), Verdict::Error(two)] = verdicts {
+            if one == two {
+                eprintln!("{day}: {one}");
+                failures += 2;
+                continue;
+            }
+        }
+        for (part, verdict) in ["one", "two"].into_iter().zip(verdicts) {
+            match verdict {
+                Verdict::Fail { expected, actual } => {
+                    eprintln!("{day} part {part}: expected {expected}, got {actual}")
+                }
+                Verdict::Error(err) => eprintln!("{day} part {part}: {err}"),
+                _ => continue,
+            }
+            failures += 1;
+        }
+    }
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::AocError;
+    use nom::error::ErrorKind;
+
+    use super::{verify, Expected, Verdict};
+    use crate::runner::Answers;
+
+    #[test]
+    fn expected_answers() {
+        let expected: Expected = toml::from_str("part_one = 24000\npart_two = \"RLEZ\"").unwrap();
+        assert_eq!(expected.part_one.as_deref(), Some("24000"));
+        assert_eq!(expected.part_two.as_deref(), Some("RLEZ"));
+
+        let expected: Expected = toml::from_str("part_one = -3").unwrap();
+        assert_eq!(expected.part_one.as_deref(), Some("-3"));
+        assert_eq!(expected.part_two, None);
+    }
+
+    #[test]
+    fn verdicts() {
+        let expected = Ok(toml::from_str("part_one = 1\npart_two = 2").unwrap());
+        let answers = Ok(Answers {
+            part_one: Some(Ok("1".to_owned())),
+            part_two: Some(Ok("3".to_owned())),
+        });
+        assert!(matches!(
+            verify(&answers, "", &expected),
+            [Verdict::Pass, Verdict::Fail { expected, actual }] if expected == "2" && actual == "3"
+        ));
+
+        let answers = Ok(Answers {
+            part_one: Some(Err(AocError::Unsolvable)),
+            part_two: None,
+        });
+        assert!(matches!(
+            verify(&answers, "", &expected),
+            [Verdict::Error(_), Verdict::Skipped]
+        ));
+
+        let answers = Ok(Answers {
+            part_one: Some(Ok("1".to_owned())),
+            part_two: Some(Ok("2".to_owned())),
+        });
+        assert!(matches!(
+            verify(&answers, "", &Ok(Expected::default())),
+            [Verdict::Unknown, Verdict::Unknown]
+        ));
+
+        let failed = Err(AocError::InvalidInput("bad"));
+        assert!(matches!(
+            verify(&failed, "", &expected),
+            [Verdict::Error(_), Verdict::Error(_)]
+        ));
+
+        let failed = Err(AocError::Parse {
+            offset: 0,
+            kind: ErrorKind::Digit,
+        });
+        assert!(matches!(
+            verify(&failed, "abc", &expected),
+            [Verdict::Error(err), Verdict::Error(_)] if err.ends_with("1 | abc\n  | ^")
+        ));
+    }
+}
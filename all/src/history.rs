@@ -0,0 +1,296 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::runner::Report;
+
+/// The history of timings, stored as one JSON [`Entry`] per line.
+///
+/// Defaults to `target/history.jsonl`, or `AOC_HISTORY` if set
+pub struct History {
+    pub path: PathBuf,
+}
+
+/// The timings from a single run of the `all` runner
+#[derive(Serialize, Deserialize)]
+pub struct Entry {
+    /// seconds since the unix epoch
+    pub time: u64,
+    /// the git commit that was checked out, with a `-dirty` suffix if there were local changes
+    pub commit: Option<String>,
+    pub machine: Machine,
+    pub repeat: usize,
+    pub days: Vec<DayTimings>,
+}
+
+/// Timings are only comparable when they come from the same machine
+#[derive(Serialize, Deserialize, PartialEq, Eq)]
+pub struct Machine {
+    pub hostname: String,
+    pub os: String,
+    pub arch: String,
+    pub cpu: Option<String>,
+    pub cpus: usize,
+}
+
+/// The median timings of a day in nanoseconds. Steps that did not run are left empty
+#[derive(Serialize, Deserialize)]
+pub struct DayTimings {
+    pub day: String,
+    pub parse_ns: u64,
+    pub part_one_ns: Option<u64>,
+    pub part_two_ns: Option<u64>,
+    pub total_ns: u64,
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+pub enum Baseline {
+    /// the most recent recorded run of the day
+    Previous,
+    /// the fastest recorded run of the day
+    Best,
+}
+
+/// A day that got slower than its baseline
+pub struct Regression {
+    pub day: String,
+    pub baseline: Duration,
+    pub commit: Option<String>,
+    pub now: Duration,
+}
+
+impl Regression {
+    /// How much slower the day got, in percent
+    pub fn slowdown(&self) -> f64 {
+        (self.now.as_secs_f64() / self.baseline.as_secs_f64() - 1.0) * 100.0
+    }
+}
+
+impl History {
+    pub fn new(path: Option<PathBuf>) -> Self {
+        let path = path
+            .or_else(|| dotenv::var("AOC_HISTORY").ok().map(PathBuf::from))
+            .unwrap_or_else(|| {
+                Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/history.jsonl")
+            });
+        Self { path }
+    }
+
+    /// Reads every entry in the history. A missing file is an empty history
+    pub fn load(&self) -> io::Result<Vec<Entry>> {
+        let file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(err),
+        };
+        BufReader::new(file)
+            .lines()
+            .filter(|line| !matches!(line, Ok(line) if line.trim().is_empty()))
+            .map(|line| Ok(serde_json::from_str(&line?)?))
+            .collect()
+    }
+
+    pub fn append(&self, entry: &Entry) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        let mut line = serde_json::to_vec(entry)?;
+        line.push(b'\n');
+        file.write_all(&line)
+    }
+}
+
+impl Entry {
+    /// Records the successful days of a run
+    pub fn new(reports: &[Report], repeat: usize) -> Self {
+        let nanos = |d: Duration| d.as_nanos() as u64;
+        let days = reports
+            .iter()
+            .filter(|report| report.failures() == 0)
+            .map(|report| {
+                let took = &report.took;
+                DayTimings {
+                    day: report.day.to_owned(),
+                    parse_ns: nanos(took.parse.median),
+                    part_one_ns: took.part_one.as_ref().map(|s| nanos(s.median)),
+                    part_two_ns: took.part_two.as_ref().map(|s| nanos(s.median)),
+                    total_ns: nanos(took.total.median),
+                }
+            })
+            .collect();
+
+        Self {
+            time: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            commit: git_commit(),
+            machine: Machine::current(),
+            repeat,
+            days,
+        }
+    }
+
+    fn day(&self, day: &str) -> Option<&DayTimings> {
+        self.days.iter().find(|timings| timings.day == day)
+    }
+}
+
+impl DayTimings {
+    /// Only timings of the same parts can be compared
+    fn same_parts(&self, other: &DayTimings) -> bool {
+        self.part_one_ns.is_some() == other.part_one_ns.is_some()
+            && self.part_two_ns.is_some() == other.part_two_ns.is_some()
+    }
+}
+
+impl Machine {
+    pub fn current() -> Self {
+        let hostname = std::fs::read_to_string("/etc/hostname")
+            .ok()
+            .or_else(|| std::env::var("HOSTNAME").ok())
+            .or_else(|| std::env::var("COMPUTERNAME").ok())
+            .map_or_else(|| "unknown".to_owned(), |h| h.trim().to_owned());
+        let cpu = std::fs::read_to_string("/proc/cpuinfo")
+            .ok()
+            .and_then(|info| {
+                info.lines()
+                    .find_map(|line| line.strip_prefix("model name"))
+                    .and_then(|line| line.split_once(':'))
+                    .map(|(_, model)| model.trim().to_owned())
+            });
+        Self {
+            hostname,
+            os: std::env::consts::OS.to_owned(),
+            arch: std::env::consts::ARCH.to_owned(),
+            cpu,
+            cpus: std::thread::available_parallelism().map_or(1, |n| n.get()),
+        }
+    }
+}
+
+fn git_commit() -> Option<String> {
+    let git = |args: &[&str]| {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    };
+    let commit = git(&["rev-parse", "--short", "HEAD"])?;
+    let dirty = git(&["status", "--porcelain"]).is_some_and(|status| !status.is_empty());
+    Some(if dirty { commit + "-dirty" } else { commit })
+}
+
+/// Finds the days in `entry` whose total time is more than `threshold` percent slower than the
+/// baseline. Only runs from the same machine with the same parts are compared
+pub fn compare(
+    history: &[Entry],
+    entry: &Entry,
+    baseline: Baseline,
+    threshold: f64,
+) -> Vec<Regression> {
+    let mut regressions = vec![];
+    for now in &entry.days {
+        let mut candidates = history
+            .iter()
+            .filter(|e| e.machine == entry.machine)
+            .filter_map(|e| Some((e, e.day(&now.day).filter(|t| t.same_parts(now))?)));
+        let base = match baseline {
+            Baseline::Previous => candidates.next_back(),
+            Baseline::Best => candidates.min_by_key(|(_, t)| t.total_ns),
+        };
+        let Some((base, timings)) = base else { continue };
+
+        let regression = Regression {
+            day: now.day.clone(),
+            baseline: Duration::from_nanos(timings.total_ns),
+            commit: base.commit.clone(),
+            now: Duration::from_nanos(now.total_ns),
+        };
+        if regression.slowdown() > threshold {
+            regressions.push(regression);
+        }
+    }
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{compare, Baseline, DayTimings, Entry, Machine};
+
+    fn machine(hostname: &str) -> Machine {
+        Machine {
+            hostname: hostname.to_owned(),
+            os: "linux".to_owned(),
+            arch: "x86_64".to_owned(),
+            cpu: None,
+            cpus: 8,
+        }
+    }
+
+    fn entry(commit: &str, hostname: &str, days: &[(&str, u64, bool)]) -> Entry {
+        Entry {
+            time: 0,
+            commit: Some(commit.to_owned()),
+            machine: machine(hostname),
+            repeat: 1,
+            days: days
+                .iter()
+                .map(|&(day, total_ns, part_two)| DayTimings {
+                    day: day.to_owned(),
+                    parse_ns: 0,
+                    part_one_ns: Some(0),
+                    part_two_ns: part_two.then_some(0),
+                    total_ns,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn regressions() {
+        let history = [
+            entry(
+                "best",
+                "here",
+                &[("day01", 100, true), ("day02", 100, true)],
+            ),
+            entry("other", "elsewhere", &[("day01", 10, true)]),
+            entry("part one", "here", &[("day01", 10, false)]),
+            entry("previous", "here", &[("day01", 200, true)]),
+        ];
+        let now = entry("now", "here", &[("day01", 150, true), ("day02", 105, true)]);
+
+        let regressions = compare(&history, &now, Baseline::Best, 10.0);
+        assert_eq!(regressions.len(), 1);
+        let r = &regressions[0];
+        assert_eq!(r.day, "day01");
+        assert_eq!(r.commit.as_deref(), Some("best"));
+        assert_eq!(
+            (r.baseline, r.now),
+            (Duration::from_nanos(100), Duration::from_nanos(150))
+        );
+        assert!((r.slowdown() - 50.0).abs() < 1e-9);
+
+        assert!(compare(&history, &now, Baseline::Previous, 10.0).is_empty());
+        assert_eq!(compare(&history, &now, Baseline::Best, 1.0).len(), 2);
+        assert!(compare(&[], &now, Baseline::Best, 0.0).is_empty());
+    }
+}
@@ -0,0 +1,56 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+/// Where the `all` runner finds each day's puzzle input.
+///
+/// By default this is `challenges/<day>/input.txt`. It can be configured with
+/// the following environment variables (or a `.env` file):
+///
+/// * `AOC_INPUT_DIR` - directory containing a folder for each day
+/// * `AOC_INPUT_FILE` - file name to read inside each day's folder,
+///   eg `alice.txt` to run against another user's inputs
+/// * `AOC_INPUT_DAY05` - path to the input for a single day, overriding the above
+pub struct Inputs {
+    pub dir: PathBuf,
+    pub file: String,
+    /// read every day from this file, ignoring all the other settings
+    pub path: Option<PathBuf>,
+}
+
+impl Inputs {
+    pub fn from_env() -> Self {
+        let dir = dotenv::var("AOC_INPUT_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| Path::new(env!("CARGO_MANIFEST_DIR")).join("../challenges"));
+        let file = dotenv::var("AOC_INPUT_FILE").unwrap_or_else(|_| "input.txt".to_owned());
+        Self {
+            dir,
+            file,
+            path: None,
+        }
+    }
+
+    pub fn path(&self, day: &str) -> PathBuf {
+        if let Some(path) = &self.path {
+            return path.clone();
+        }
+        match dotenv::var(format!("AOC_INPUT_{}", day.to_uppercase())) {
+            Ok(path) => PathBuf::from(path),
+            Err(_) => self.dir.join(day).join(&self.file),
+        }
+    }
+
+    /// Reads the input for the day.
+    ///
+    /// The challenges borrow from their input for the lifetime of the program,
+    /// so the contents are leaked.
+    pub fn load(&self, day: &str) -> Result<&'static str, (PathBuf, io::Error)> {
+        let path = self.path(day);
+        match std::fs::read_to_string(&path) {
+            Ok(input) => Ok(Box::leak(input.into_boxed_str())),
+            Err(err) => Err((path, err)),
+        }
+    }
+}
@@ -1,72 +1,242 @@
-use std::time::{Duration, Instant};
-
-use aoc::Parser;
-
-static DAY01: &str = include_str!("../../challenges/day01/input.txt");
-static DAY02: &str = include_str!("../../challenges/day02/input.txt");
-static DAY03: &str = include_str!("../../challenges/day03/input.txt");
-static DAY04: &str = include_str!("../../challenges/day04/input.txt");
-static DAY05: &str = include_str!("../../challenges/day05/input.txt");
-static DAY06: &str = include_str!("../../challenges/day06/input.txt");
-static DAY07: &str = include_str!("../../challenges/day07/input.txt");
-static DAY08: &str = include_str!("../../challenges/day08/input.txt");
-static DAY09: &str = include_str!("../../challenges/day09/input.txt");
-static DAY10: &str = include_str!("../../challenges/day10/input.txt");
-static DAY11: &str = include_str!("../../challenges/day11/input.txt");
-static DAY12: &str = include_str!("../../challenges/day12/input.txt");
-static DAY13: &str = include_str!("../../challenges/day13/input.txt");
-static DAY14: &str = include_str!("../../challenges/day14/input.txt");
-static DAY15: &str = include_str!("../../challenges/day15/input.txt");
-static DAY16: &str = include_str!("../../challenges/day16/input.txt");
-static DAY17: &str = include_str!("../../challenges/day17/input.txt");
-static DAY18: &str = include_str!("../../challenges/day18/input.txt");
-// static DAY19: &str = include_str!("../../challenges/day19/input.txt");
-// static DAY20: &str = include_str!("../../challenges/day20/input.txt");
-// static DAY21: &str = include_str!("../../challenges/day21/input.txt");
-// static DAY22: &str = include_str!("../../challenges/day22/input.txt");
-// static DAY23: &str = include_str!("../../challenges/day23/input.txt");
-// static DAY24: &str = include_str!("../../challenges/day24/input.txt");
-// static DAY25: &str = include_str!("../../challenges/day25/input.txt");
-
-fn main() {
-    let start = Instant::now();
-    let mut results = Vec::with_capacity(25);
-    results.push(check::<day01::Solution>(DAY01));
-    results.push(check::<day02::Solution>(DAY02));
-    results.push(check::<day03::Solution>(DAY03));
-    results.push(check::<day04::Solution>(DAY04));
-    results.push(check::<day05::Solution>(DAY05));
-    results.push(check::<day06::Solution>(DAY06));
-    results.push(check::<day07::Solution>(DAY07));
-    results.push(check::<day08::Solution>(DAY08));
-    results.push(check::<day09::Solution>(DAY09));
-    results.push(check::<day10::Solution>(DAY10));
-    results.push(check::<day11::Solution>(DAY11));
-    results.push(check::<day12::Solution>(DAY12));
-    results.push(check::<day13::Solution>(DAY13));
-    results.push(check::<day14::Solution>(DAY14));
-    results.push(check::<day15::Solution<4000000>>(DAY15));
-    results.push(check::<day16::Solution>(DAY16));
-    results.push(check::<day17::Solution>(DAY17));
-    results.push(check::<day18::Solution>(DAY18));
-    // results.push(check::<day19::Solution>(DAY19));
-    // results.push(check::<day20::Solution>(DAY20));
-    // results.push(check::<day21::Solution>(DAY21));
-    // results.push(check::<day22::Solution>(DAY22));
-    // results.push(check::<day23::Solution>(DAY23));
-    // results.push(check::<day24::Solution>(DAY24));
-    // results.push(check::<day25::Solution>(DAY25));
-
-    println!("Running {} days took {:?}", results.len(), start.elapsed());
-    println!("{results:#?}");
+use std::{
+    ops::RangeInclusive,
+    path::PathBuf,
+    process::ExitCode,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use clap::Parser;
+use history::{Baseline, History};
+use input::Inputs;
+use output::Format;
+use rayon::prelude::*;
+use runner::{Options, Report};
+
+// each day registers its solution with `aoc::register!`,
+// but its crate has to be linked in for the registration to be seen.
+// `build.rs` links every day in the dependencies
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+mod alloc;
+mod answers;
+mod history;
+mod input;
+mod output;
+mod runner;
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static ALLOC: alloc::Counting = alloc::Counting;
+
+/// Runs the Advent of Code 2022 solutions
+#[derive(Parser)]
+struct Args {
+    /// Days to run, eg `5`, `5..=9` or `5..9`. Can be given multiple times.
+    /// Runs every day by default
+    #[arg(short, long = "day", value_name = "DAYS")]
+    days: Vec<DayRange>,
+
+    /// Only run this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input file to use, or a directory containing a folder per day
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Number of times to run each day
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    repeat: u64,
+
+    /// Compare the answers against each day's `answers.toml` and print a pass/fail table
+    #[arg(long, conflicts_with = "format")]
+    verify: bool,
+
+    /// Run the days, and the two parts of each day, at the same time.
+    /// Uses a thread per core unless `RAYON_NUM_THREADS` is set
+    #[arg(long)]
+    parallel: bool,
+
+    /// How to print the results
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// File to record the timings of each run in. Defaults to `target/history.jsonl`
+    #[arg(long, value_name = "FILE")]
+    history: Option<PathBuf>,
+
+    /// Do not record the timings of this run
+    #[arg(long)]
+    no_history: bool,
+
+    /// Report days that got slower than the previous or best recorded run
+    #[arg(long, value_enum, value_name = "BASELINE")]
+    compare: Option<Baseline>,
+
+    /// How many percent slower a day has to get to be reported by --compare
+    #[arg(long, default_value_t = 10.0, value_name = "PERCENT")]
+    threshold: f64,
 }
 
-fn check<C: Parser + Clone>(input: &'static str) -> (String, String, Duration) {
+#[derive(Clone)]
+struct DayRange(RangeInclusive<u8>);
+
+impl FromStr for DayRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let day = |s: &str| {
+            s.trim()
+                .parse::<u8>()
+                .map_err(|_| format!("`{s}` is not a day"))
+        };
+        let range = if let Some((start, end)) = s.split_once("..=") {
+            day(start)?..=day(end)?
+        } else if let Some((start, end)) = s.split_once("..") {
+            day(start)?..=day(end)?.saturating_sub(1)
+        } else {
+            let day = day(s)?;
+            day..=day
+        };
+        Ok(Self(range))
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let mut inputs = Inputs::from_env();
+    match args.input {
+        Some(dir) if dir.is_dir() => inputs.dir = dir,
+        path => inputs.path = path,
+    }
+
+    let options = Options {
+        part_one: args.part != Some(2),
+        part_two: args.part != Some(1),
+        repeat: args.repeat as usize,
+        parallel: args.parallel,
+    };
+
+    let days: Vec<_> = aoc::days()
+        .into_iter()
+        .filter(|day| {
+            let n = day.name[3..].parse::<u8>().unwrap_or_default();
+            args.days.is_empty() || args.days.iter().any(|r| r.0.contains(&n))
+        })
+        .collect();
+
+    if inputs.path.is_some() && days.len() > 1 {
+        eprintln!("an input file can only be used with a single --day");
+        return ExitCode::FAILURE;
+    }
+
     let start = Instant::now();
-    let challenge = C::parse(input).unwrap().1;
-    let p1 = challenge.clone().part_one();
-    let p2 = challenge.part_two();
-    let took = start.elapsed();
+    let run = |day: &&aoc::Day| match inputs.load(day.name) {
+        Ok(input) => Some(runner::run(day, input, options)),
+        Err((path, err)) => {
+            eprintln!(
+                "skipping {}: could not read {}: {err}",
+                day.name,
+                path.display()
+            );
+            None
+        }
+    };
+    let reports: Vec<Report> = if args.parallel {
+        days.par_iter().filter_map(run).collect()
+    } else {
+        days.iter().filter_map(run).collect()
+    };
+    if args.format == Format::Text {
+        let took = start.elapsed();
+        if args.parallel {
+            let cpu: Duration = reports.iter().map(|report| report.took.total.sum).sum();
+            println!(
+                "Running {} days took {took:?} on {} threads ({cpu:?} summed over the days)",
+                reports.len(),
+                rayon::current_num_threads(),
+            );
+        } else {
+            println!("Running {} days took {took:?}", reports.len());
+        }
+    }
+
+    let mut failures = if args.verify {
+        let results: Vec<_> = reports
+            .iter()
+            .map(|report| {
+                let expected = answers::Expected::load(&answers::Expected::path(report.day));
+                let verdicts = answers::verify(&report.answers, report.input, &expected);
+                (report.day, verdicts)
+            })
+            .collect();
+        answers::print_table(&results)
+    } else {
+        let failures = reports.iter().map(Report::failures).sum();
+        match output::print(args.format, &reports) {
+            Ok(()) => failures,
+            Err(err) => {
+                eprintln!("could not write the results: {err}");
+                failures + 1
+            }
+        }
+    };
+
+    let history = History::new(args.history);
+    let entry = history::Entry::new(&reports, options.repeat);
+    if let Some(baseline) = args.compare {
+        match history.load() {
+            Ok(entries) => {
+                let regressions = history::compare(&entries, &entry, baseline, args.threshold);
+                for r in &regressions {
+                    eprintln!(
+                        "{} got {:.1}% slower: {:?} -> {:?} (compared to {})",
+                        r.day,
+                        r.slowdown(),
+                        r.baseline,
+                        r.now,
+                        r.commit.as_deref().unwrap_or("unknown commit"),
+                    );
+                }
+                failures += regressions.len();
+            }
+            Err(err) => {
+                eprintln!("could not read history {}: {err}", history.path.display());
+                failures += 1;
+            }
+        }
+    }
+    if !args.no_history {
+        if let Err(err) = history.append(&entry) {
+            eprintln!("could not record history {}: {err}", history.path.display());
+        }
+    }
+
+    if failures > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DayRange;
+
+    fn days(s: &str) -> Result<Vec<u8>, String> {
+        s.parse::<DayRange>().map(|range| range.0.collect())
+    }
 
-    (p1.to_string(), p2.to_string(), took)
+    #[test]
+    fn day_ranges() {
+        assert_eq!(days("5"), Ok(vec![5]));
+        assert_eq!(days("5..=7"), Ok(vec![5, 6, 7]));
+        assert_eq!(days("5..7"), Ok(vec![5, 6]));
+        assert_eq!(days(" 5 ..= 6 "), Ok(vec![5, 6]));
+        assert_eq!(days("5..5"), Ok(vec![]));
+        assert_eq!(days("five"), Err("`five` is not a day".to_owned()));
+        assert_eq!(days("5..x"), Err("`x` is not a day".to_owned()));
+    }
 }
@@ -0,0 +1,398 @@
+use std::{
+    io::{self, Write},
+    time::Duration,
+};
+
+use serde::Serialize;
+
+use crate::{
+    alloc::{Allocs, Bytes},
+    runner::{Answer, Report, Stats},
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// Human readable output
+    Text,
+    /// A JSON array with an object per day
+    Json,
+    /// CSV with a header row
+    Csv,
+    /// A Markdown table
+    Markdown,
+}
+
+/// One day of results, flattened for the machine readable formats.
+/// Times are the median in nanoseconds. Parts that were not run are left empty,
+/// as are the allocations unless the counting allocator is installed.
+#[derive(Serialize)]
+struct Row {
+    day: &'static str,
+    part_one: Option<String>,
+    part_two: Option<String>,
+    parse_ns: u128,
+    part_one_ns: Option<u128>,
+    part_two_ns: Option<u128>,
+    total_ns: u128,
+    /// whether parsing took most of the total time, see [`aoc::parse_dominates`]
+    parse_dominates: bool,
+    parse_allocs: Option<u64>,
+    parse_alloc_bytes: Option<u64>,
+    parse_peak_bytes: Option<u64>,
+    part_one_allocs: Option<u64>,
+    part_one_alloc_bytes: Option<u64>,
+    part_one_peak_bytes: Option<u64>,
+    part_two_allocs: Option<u64>,
+    part_two_alloc_bytes: Option<u64>,
+    part_two_peak_bytes: Option<u64>,
+    /// why the day or its parts failed
+    error: Option<String>,
+}
+
+impl Row {
+    fn new(report: &Report) -> Self {
+        let mut errors = vec![];
+        let mut answer = |part: &str, answer: &Option<Answer>| match answer {
+            Some(Ok(answer)) => Some(answer.clone()),
+            Some(Err(err)) => {
+                errors.push(format!("part {part} failed: {err}"));
+                None
+            }
+            None => None,
+        };
+        let (part_one, part_two) = match &report.answers {
+            Ok(answers) => (
+                answer("one", &answers.part_one),
+                answer("two", &answers.part_two),
+            ),
+            Err(err) => {
+                errors.push(format!(
+                    "failed to parse: {}",
+                    err.report(report.input).trim_end()
+                ));
+                (None, None)
+            }
+        };
+
+        let took = &report.took;
+        let allocs = report.allocs.as_ref();
+        let parse = allocs.map(|a| a.parse);
+        let one = allocs.and_then(|a| a.part_one);
+        let two = allocs.and_then(|a| a.part_two);
+        Row {
+            day: report.day,
+            part_one,
+            part_two,
+            parse_ns: took.parse.median.as_nanos(),
+            part_one_ns: took.part_one.as_ref().map(|s| s.median.as_nanos()),
+            part_two_ns: took.part_two.as_ref().map(|s| s.median.as_nanos()),
+            total_ns: took.total.median.as_nanos(),
+            parse_dominates: took.parse_dominates(),
+            parse_allocs: parse.map(|a| a.count),
+            parse_alloc_bytes: parse.map(|a| a.bytes),
+            parse_peak_bytes: parse.map(|a| a.peak),
+            part_one_allocs: one.map(|a| a.count),
+            part_one_alloc_bytes: one.map(|a| a.bytes),
+            part_one_peak_bytes: one.map(|a| a.peak),
+            part_two_allocs: two.map(|a| a.count),
+            part_two_alloc_bytes: two.map(|a| a.bytes),
+            part_two_peak_bytes: two.map(|a| a.peak),
+            error: (!errors.is_empty()).then(|| errors.join("; ")),
+        }
+    }
+}
+
+/// Prints the reports to stdout. Stdout being closed early, eg by piping into `head`,
+/// is not an error
+pub fn print(format: Format, reports: &[Report]) -> io::Result<()> {
+    match write(format, reports, &mut io::stdout().lock()) {
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        result => result,
+    }
+}
+
+fn write(format: Format, reports: &[Report], w: &mut impl Write) -> io::Result<()> {
+    if format == Format::Text {
+        return reports.iter().try_for_each(|report| text(w, report));
+    }
+
+    let rows: Vec<_> = reports.iter().map(Row::new).collect();
+    match format {
+        Format::Text => unreachable!(),
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *w, &rows)?;
+            writeln!(w)?;
+        }
+        Format::Csv => {
+            let mut w = csv::Writer::from_writer(w);
+            for row in &rows {
+                w.serialize(row)?;
+            }
+            w.flush()?;
+        }
+        Format::Markdown => markdown(w, reports, &rows)?,
+    }
+
+    for row in &rows {
+        if let Some(err) = &row.error {
+            eprintln!("{} {err}", row.day);
+        }
+    }
+    Ok(())
+}
+
+fn text(w: &mut impl Write, report: &Report) -> io::Result<()> {
+    writeln!(w, "{}", report.day)?;
+    match &report.answers {
+        Err(err) => eprintln!(
+            "{} failed to parse: {}",
+            report.day,
+            err.report(report.input).trim_end()
+        ),
+        Ok(answers) => {
+            for (part, answer) in [("one", &answers.part_one), ("two", &answers.part_two)] {
+                match answer {
+                    Some(Ok(answer)) => writeln!(w, "\tAnswer to part {part}: {answer}")?,
+                    Some(Err(err)) => eprintln!("{} part {part} failed: {err}", report.day),
+                    None => {}
+                }
+            }
+        }
+    }
+
+    let took = &report.took;
+    let total = &took.total;
+    if total.runs > 1 {
+        writeln!(
+            w,
+            "\ttook: {:?} (min {:?}, max {:?} over {} runs)",
+            total.median, total.min, total.max, total.runs
+        )?;
+    } else {
+        writeln!(w, "\ttook: {:?}", total.median)?;
+    }
+
+    write!(w, "\t\tparse: {:?}", took.parse.median)?;
+    if let Some(part_one) = &took.part_one {
+        write!(w, ", part one: {:?}", part_one.median)?;
+    }
+    if let Some(part_two) = &took.part_two {
+        write!(w, ", part two: {:?}", part_two.median)?;
+    }
+    writeln!(w)?;
+    if let Some(allocs) = &report.allocs {
+        write!(w, "\t\tparse: {}", allocs.parse)?;
+        if let Some(part_one) = &allocs.part_one {
+            write!(w, ", part one: {part_one}")?;
+        }
+        if let Some(part_two) = &allocs.part_two {
+            write!(w, ", part two: {part_two}")?;
+        }
+        writeln!(w)?;
+    }
+    if took.parse_dominates() {
+        writeln!(w, "\tnote: most of the time is spent parsing")?;
+    }
+    Ok(())
+}
+
+fn markdown(w: &mut impl Write, reports: &[Report], rows: &[Row]) -> io::Result<()> {
+    fn time(stats: Option<&Stats>) -> String {
+        stats.map_or_else(String::new, |s| format!("{:?}", s.median))
+    }
+
+    let allocs = reports.iter().any(|report| report.allocs.is_some());
+    if allocs {
+        writeln!(w, "| Day | Part one | Part two | Parse | Part one time | Part two time | Total | Allocations | Peak memory |")?;
+        writeln!(w, "|-----|----------|----------|------:|--------------:|--------------:|------:|------------:|------------:|")?;
+    } else {
+        writeln!(
+            w,
+            "| Day | Part one | Part two | Parse | Part one time | Part two time | Total |"
+        )?;
+        writeln!(
+            w,
+            "|-----|----------|----------|------:|--------------:|--------------:|------:|"
+        )?;
+    }
+    let mut total = Duration::ZERO;
+    for (report, row) in reports.iter().zip(rows) {
+        let took = &report.took;
+        total += took.total.median;
+        write!(
+            w,
+            "| {} | {} | {} | {} | {} | {} | {} |",
+            row.day,
+            row.part_one.as_deref().unwrap_or_default(),
+            row.part_two.as_deref().unwrap_or_default(),
+            if row.parse_dominates {
+                format!("**{}**", time(Some(&took.parse)))
+            } else {
+                time(Some(&took.parse))
+            },
+            time(took.part_one.as_ref()),
+            time(took.part_two.as_ref()),
+            time(Some(&took.total)),
+        )?;
+        if allocs {
+            let steps: Vec<Allocs> = report
+                .allocs
+                .iter()
+                .flat_map(|a| {
+                    [Some(a.parse), a.part_one, a.part_two]
+                        .into_iter()
+                        .flatten()
+                })
+                .collect();
+            let count: u64 = steps.iter().map(|a| a.count).sum();
+            let bytes = Bytes(steps.iter().map(|a| a.bytes).sum());
+            let peak = Bytes(steps.iter().map(|a| a.peak).max().unwrap_or_default());
+            write!(w, " {count} ({bytes}) | {peak} |")?;
+        }
+        writeln!(w)?;
+    }
+    if allocs {
+        writeln!(w, "| **Total** | | | | | | {total:?} | | |")?;
+    } else {
+        writeln!(w, "| **Total** | | | | | | {total:?} |")?;
+    }
+    if rows.iter().any(|row| row.parse_dominates) {
+        writeln!(w)?;
+        writeln!(
+            w,
+            "Parse times in bold take up most of the day's total time."
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io, time::Duration};
+
+    use aoc::AocError;
+    use nom::error::ErrorKind;
+
+    use super::{write, Format, Row};
+    use crate::runner::{Answers, Report, Stats, Timings};
+
+    fn stats(micros: u64) -> Stats {
+        let took = Duration::from_micros(micros);
+        Stats {
+            runs: 1,
+            sum: took,
+            min: took,
+            median: took,
+            max: took,
+        }
+    }
+
+    fn report() -> Report {
+        Report {
+            day: "day01",
+            input: "1000\nabc\n",
+            answers: Ok(Answers {
+                part_one: Some(Ok("24000".to_owned())),
+                part_two: Some(Err(AocError::Unsolvable)),
+            }),
+            took: Timings {
+                parse: stats(3),
+                part_one: Some(stats(1)),
+                part_two: Some(stats(1)),
+                total: stats(5),
+            },
+            allocs: None,
+        }
+    }
+
+    fn written(format: Format) -> String {
+        let mut out = vec![];
+        write(format, &[report()], &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn rows() {
+        let row = Row::new(&report());
+        assert_eq!(row.part_one.as_deref(), Some("24000"));
+        assert_eq!(row.part_two, None);
+        assert_eq!(
+            (row.parse_ns, row.part_one_ns, row.total_ns),
+            (3000, Some(1000), 5000)
+        );
+        assert!(row.parse_dominates);
+        assert_eq!(row.parse_allocs, None);
+        assert_eq!(
+            row.error.as_deref(),
+            Some("part two failed: no solution found")
+        );
+
+        let failed = Report {
+            answers: Err(AocError::InvalidInput("bad")),
+            ..report()
+        };
+        let row = Row::new(&failed);
+        assert_eq!((row.part_one, row.part_two), (None, None));
+        assert_eq!(
+            row.error.as_deref(),
+            Some("failed to parse: invalid input: bad")
+        );
+
+        let failed = Report {
+            answers: Err(AocError::Parse {
+                offset: 5,
+                kind: ErrorKind::Digit,
+            }),
+            ..report()
+        };
+        assert_eq!(
+            Row::new(&failed).error.as_deref(),
+            Some("failed to parse: expected number at line 2, column 1\n  |\n2 | abc\n  | ^")
+        );
+    }
+
+    #[test]
+    fn formats() {
+        let csv = written(Format::Csv);
+        let mut lines = csv.lines();
+        assert!(lines
+            .next()
+            .unwrap()
+            .starts_with("day,part_one,part_two,parse_ns,"));
+        assert!(lines
+            .next()
+            .unwrap()
+            .starts_with("day01,24000,,3000,1000,1000,5000,true,"));
+        assert_eq!(lines.next(), None);
+
+        let json: serde_json::Value = serde_json::from_str(&written(Format::Json)).unwrap();
+        assert_eq!(json[0]["day"], "day01");
+        assert_eq!(json[0]["part_two"], serde_json::Value::Null);
+
+        let markdown = written(Format::Markdown);
+        assert!(markdown.contains("| day01 | 24000 |  | **3µs** | 1µs | 1µs | 5µs |\n"));
+
+        let text = written(Format::Text);
+        assert!(text.starts_with("day01\n\tAnswer to part one: 24000\n\ttook: 5µs\n"));
+    }
+
+    struct Closed;
+
+    impl io::Write for Closed {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn closed_output() {
+        for format in [Format::Text, Format::Json, Format::Csv, Format::Markdown] {
+            let err = write(format, &[report()], &mut Closed).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use std::time::{Duration, Instant};
+
+use aoc::{AocError, Day};
+
+use crate::alloc::{self, Allocs};
+
+pub type Answer = Result<String, AocError>;
+
+pub struct Report {
+    pub day: &'static str,
+    /// the input the day was run on, to show where parsing failed
+    pub input: &'static str,
+    /// `Err` if the input could not be parsed
+    pub answers: Result<Answers, AocError>,
+    pub took: Timings,
+    /// `None` unless the counting allocator is installed
+    pub allocs: Option<Allocations>,
+}
+
+impl Report {
+    /// Number of steps that failed
+    pub fn failures(&self) -> usize {
+        match &self.answers {
+            Err(_) => 1,
+            Ok(answers) => [&answers.part_one, &answers.part_two]
+                .into_iter()
+                .filter(|answer| matches!(answer, Some(Err(_))))
+                .count(),
+        }
+    }
+}
+
+/// Answers for the parts that were run
+pub struct Answers {
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+}
+
+/// How long each step of a day took
+pub struct Timings {
+    pub parse: Stats,
+    pub part_one: Option<Stats>,
+    pub part_two: Option<Stats>,
+    pub total: Stats,
+}
+
+impl Timings {
+    /// Whether parsing took most of the time, comparing medians
+    pub fn parse_dominates(&self) -> bool {
+        aoc::parse_dominates(self.parse.median, self.total.median)
+    }
+}
+
+/// The allocations made by each step of the first run of a day.
+/// These include boxing the parsed challenge and formatting the answers
+pub struct Allocations {
+    pub parse: Allocs,
+    pub part_one: Option<Allocs>,
+    pub part_two: Option<Allocs>,
+}
+
+pub struct Stats {
+    pub runs: usize,
+    /// the time taken by all of the runs together
+    pub sum: Duration,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn new(mut times: Vec<Duration>) -> Self {
+        times.sort_unstable();
+        Self {
+            runs: times.len(),
+            sum: times.iter().sum(),
+            min: times.first().copied().unwrap_or_default(),
+            median: times.get(times.len() / 2).copied().unwrap_or_default(),
+            max: times.last().copied().unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Options {
+    pub part_one: bool,
+    pub part_two: bool,
+    pub repeat: usize,
+    /// run the two parts at the same time on the rayon thread pool
+    pub parallel: bool,
+}
+
+fn timed<T>(times: &mut Vec<Duration>, f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let t = f();
+    times.push(start.elapsed());
+    t
+}
+
+/// Runs the selected parts of a challenge `repeat` times. A parse failure fails the whole day,
+/// but a failure in one part does not stop the other from running.
+///
+/// Parsing and each part are timed separately. When both parts are run, the parsed challenge
+/// is cloned for part one, which is not included in the timings. The total is the sum of
+/// the steps, even if the parts ran in parallel.
+///
+/// The answers and allocations are taken from the first run.
+pub fn run(day: &Day, input: &'static str, options: Options) -> Report {
+    let mut parse = Vec::with_capacity(options.repeat);
+    let mut part_one = Vec::with_capacity(options.repeat);
+    let mut part_two = Vec::with_capacity(options.repeat);
+    let mut total = Vec::with_capacity(options.repeat);
+    let mut answers = None;
+    let mut allocs = None;
+
+    for _ in 0..options.repeat {
+        let (challenge, parse_allocs) = alloc::counted(|| timed(&mut parse, || day.parse(input)));
+        let challenge = match challenge {
+            Ok(challenge) => challenge,
+            Err(err) => {
+                total.extend(parse.last());
+                answers = Some(Err(err));
+                allocs.get_or_insert(Allocations {
+                    parse: parse_allocs,
+                    part_one: None,
+                    part_two: None,
+                });
+                break;
+            }
+        };
+        let (one, two) = match (options.part_one, options.part_two) {
+            (true, true) => {
+                let clone = challenge.boxed_clone();
+                let one = || alloc::counted(|| timed(&mut part_one, || clone.part_one()));
+                let two = || alloc::counted(|| timed(&mut part_two, || challenge.part_two()));
+                let (one, two) = if options.parallel {
+                    rayon::join(one, two)
+                } else {
+                    (one(), two())
+                };
+                (Some(one), Some(two))
+            }
+            (true, false) => (
+                Some(alloc::counted(|| {
+                    timed(&mut part_one, || challenge.part_one())
+                })),
+                None,
+            ),
+            (false, true) => (
+                None,
+                Some(alloc::counted(|| {
+                    timed(&mut part_two, || challenge.part_two())
+                })),
+            ),
+            (false, false) => (None, None),
+        };
+        let (one, one_allocs) = one.unzip();
+        let (two, two_allocs) = two.unzip();
+        let run = parse.last().copied().unwrap_or_default()
+            + part_one.last().copied().unwrap_or_default()
+            + part_two.last().copied().unwrap_or_default();
+        total.push(run);
+
+        answers.get_or_insert(Ok(Answers {
+            part_one: one,
+            part_two: two,
+        }));
+        allocs.get_or_insert(Allocations {
+            parse: parse_allocs,
+            part_one: one_allocs,
+            part_two: two_allocs,
+        });
+    }
+
+    Report {
+        day: day.name,
+        input,
+        answers: answers.unwrap_or(Ok(Answers {
+            part_one: None,
+            part_two: None,
+        })),
+        took: Timings {
+            parse: Stats::new(parse),
+            part_one: (!part_one.is_empty()).then(|| Stats::new(part_one)),
+            part_two: (!part_two.is_empty()).then(|| Stats::new(part_two)),
+            total: Stats::new(total),
+        },
+        allocs: allocs.filter(|_| alloc::ENABLED),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc::{Challenge, Day, Parser};
+    use nom::IResult;
+
+    use super::{run, Answers, Options, Stats};
+
+    /// Answers with where each part ran, `pool` if it was on the rayon thread pool
+    #[derive(Clone)]
+    struct Threads;
+
+    impl Parser for Threads {
+        fn parse(input: &'static str) -> IResult<&'static str, Self> {
+            Ok((&input[input.len()..], Self))
+        }
+    }
+
+    impl Challenge for Threads {
+        const NAME: &'static str = "threads";
+
+        type Output1 = &'static str;
+        fn part_one(self) -> aoc::Result<Self::Output1> {
+            Ok(thread())
+        }
+
+        type Output2 = &'static str;
+        fn part_two(self) -> aoc::Result<Self::Output2> {
+            Ok(thread())
+        }
+    }
+
+    fn thread() -> &'static str {
+        match rayon::current_thread_index() {
+            Some(_) => "pool",
+            None => "main",
+        }
+    }
+
+    fn answers(answers: &Answers) -> [Option<&str>; 2] {
+        [&answers.part_one, &answers.part_two]
+            .map(|answer| answer.as_ref().map(|answer| answer.as_deref().unwrap()))
+    }
+
+    #[test]
+    fn stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::new(vec![ms(5), ms(1), ms(3), ms(2)]);
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.max, ms(5));
+        assert_eq!(stats.sum, ms(11));
+
+        let empty = Stats::new(vec![]);
+        assert_eq!((empty.runs, empty.min, empty.max), (0, ms(0), ms(0)));
+        assert_eq!(empty.sum, ms(0));
+    }
+
+    #[test]
+    fn parallel() {
+        let day = Day::new::<Threads>();
+        let options = Options {
+            part_one: true,
+            part_two: true,
+            repeat: 3,
+            parallel: false,
+        };
+        let report = run(&day, "", options);
+        let sequential = report.answers.as_ref().unwrap();
+        assert_eq!(answers(sequential), [Some("main"), Some("main")]);
+
+        let report = run(
+            &day,
+            "",
+            Options {
+                parallel: true,
+                ..options
+            },
+        );
+        let parallel = report.answers.as_ref().unwrap();
+        assert_eq!(answers(parallel), [Some("pool"), Some("pool")]);
+
+        // the total is still the sum of the steps of each run
+        let took = &report.took;
+        let (one, two) = (
+            took.part_one.as_ref().unwrap(),
+            took.part_two.as_ref().unwrap(),
+        );
+        assert_eq!(took.total.runs, 3);
+        assert_eq!(took.total.sum, took.parse.sum + one.sum + two.sum);
+    }
+
+    #[test]
+    fn one_part() {
+        let day = Day::new::<Threads>();
+        let options = Options {
+            part_one: false,
+            part_two: true,
+            repeat: 2,
+            parallel: true,
+        };
+        let report = run(&day, "", options);
+        assert_eq!(
+            answers(report.answers.as_ref().unwrap()),
+            [None, Some("main")]
+        );
+        assert!(report.took.part_one.is_none());
+        assert_eq!(report.took.part_two.as_ref().unwrap().runs, 2);
+    }
+}
@@ -0,0 +1,213 @@
+use std::{
+    fmt, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{state, SubmitOutcome};
+
+/// Every answer submitted for a day, and what the server said about it.
+///
+/// Stored in `challenges/<day>/attempts.toml`, so that answers that are known to be wrong
+/// are never sent twice and the server's cooldown is respected between runs
+#[derive(Serialize, Deserialize, Default)]
+pub struct Attempts {
+    /// unix time in seconds before which no answer should be submitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_until: Option<u64>,
+    #[serde(default, rename = "attempt")]
+    pub attempts: Vec<Attempt>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Attempt {
+    pub level: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// The outcome of an answer that was checked by the server
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+/// Why an answer was not submitted
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    /// the level was already solved, with this answer
+    Solved(String),
+    /// this exact answer was already submitted
+    AlreadyTried(Verdict),
+    /// the answer is at least as high as an answer that was too high
+    TooHigh(String),
+    /// the answer is at most as low as an answer that was too low
+    TooLow(String),
+    /// the server asked us to wait this much longer
+    Cooldown(Duration),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::Solved(answer) => write!(f, "already solved with {answer}"),
+            Refusal::AlreadyTried(verdict) => {
+                write!(f, "this answer was already submitted and was {verdict:?}")
+            }
+            Refusal::TooHigh(bound) => write!(f, "{bound} was already too high"),
+            Refusal::TooLow(bound) => write!(f, "{bound} was already too low"),
+            Refusal::Cooldown(d) => write!(f, "must wait another {d:?} before submitting"),
+        }
+    }
+}
+
+/// The current unix time in seconds
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+impl Attempts {
+    pub fn path(day: &str) -> PathBuf {
+        Path::new("challenges").join(day).join("attempts.toml")
+    }
+
+    /// Reads the attempts from `path`. A missing file means nothing was submitted yet
+    pub fn load(path: &Path) -> io::Result<Self> {
+        Ok(state::load(path)?.unwrap_or_default())
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        state::save(self, path)
+    }
+
+    /// Checks whether `answer` is worth submitting for the level at unix time `now`
+    pub fn check(&self, level: u8, answer: &str, now: u64) -> Result<(), Refusal> {
+        let attempts = || self.attempts.iter().filter(|a| a.level == level);
+
+        if let Some(solved) = attempts().find(|a| a.verdict == Verdict::Correct) {
+            return Err(Refusal::Solved(solved.answer.clone()));
+        }
+        if let Some(tried) = attempts().find(|a| a.answer == answer) {
+            return Err(Refusal::AlreadyTried(tried.verdict));
+        }
+
+        if let Ok(n) = answer.parse::<i128>() {
+            let bound = |verdict| {
+                attempts()
+                    .filter(move |a| a.verdict == verdict)
+                    .filter_map(|a| Some((a.answer.parse::<i128>().ok()?, a)))
+            };
+            if let Some((_, high)) = bound(Verdict::TooHigh)
+                .filter(|(h, _)| n >= *h)
+                .min_by_key(|(h, _)| *h)
+            {
+                return Err(Refusal::TooHigh(high.answer.clone()));
+            }
+            if let Some((_, low)) = bound(Verdict::TooLow)
+                .filter(|(l, _)| n <= *l)
+                .max_by_key(|(l, _)| *l)
+            {
+                return Err(Refusal::TooLow(low.answer.clone()));
+            }
+        }
+
+        match self.wait_until {
+            Some(until) if until > now => Err(Refusal::Cooldown(Duration::from_secs(until - now))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Records the outcome of submitting `answer` at unix time `now`
+    pub fn record(&mut self, level: u8, answer: &str, outcome: &SubmitOutcome, now: u64) {
+        self.wait_until = Some(now + outcome.cooldown().as_secs());
+        let verdict = match outcome {
+            SubmitOutcome::Correct => Verdict::Correct,
+            SubmitOutcome::TooHigh => Verdict::TooHigh,
+            SubmitOutcome::TooLow => Verdict::TooLow,
+            SubmitOutcome::Wrong => Verdict::Wrong,
+            // the answer was not checked
+            SubmitOutcome::Wait(_) | SubmitOutcome::AlreadySolved | SubmitOutcome::Unknown(_) => {
+                return
+            }
+        };
+        self.attempts.push(Attempt {
+            level,
+            answer: answer.to_owned(),
+            verdict,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Attempts, Refusal, Verdict};
+    use crate::SubmitOutcome;
+
+    #[test]
+    fn refuses_known_answers() {
+        let mut attempts = Attempts::default();
+        attempts.record(1, "100", &SubmitOutcome::TooHigh, 0);
+        attempts.record(1, "10", &SubmitOutcome::TooLow, 100);
+        attempts.record(1, "hello", &SubmitOutcome::Wrong, 200);
+
+        assert_eq!(
+            attempts.check(1, "hello", 1000),
+            Err(Refusal::AlreadyTried(Verdict::Wrong))
+        );
+        assert_eq!(
+            attempts.check(1, "150", 1000),
+            Err(Refusal::TooHigh("100".to_owned()))
+        );
+        assert_eq!(
+            attempts.check(1, "5", 1000),
+            Err(Refusal::TooLow("10".to_owned()))
+        );
+        assert_eq!(attempts.check(1, "50", 1000), Ok(()));
+        assert_eq!(attempts.check(2, "150", 1000), Ok(()));
+
+        attempts.record(1, "50", &SubmitOutcome::Correct, 1000);
+        assert_eq!(
+            attempts.check(1, "51", 2000),
+            Err(Refusal::Solved("50".to_owned()))
+        );
+    }
+
+    #[test]
+    fn cooldown() {
+        let mut attempts = Attempts::default();
+        attempts.record(1, "1", &SubmitOutcome::Wait(Duration::from_secs(30)), 1000);
+        assert_eq!(
+            attempts.check(1, "1", 1010),
+            Err(Refusal::Cooldown(Duration::from_secs(20)))
+        );
+        assert_eq!(attempts.check(1, "1", 1030), Ok(()));
+
+        attempts.record(1, "1", &SubmitOutcome::Wrong, 1030);
+        assert_eq!(
+            attempts.check(1, "2", 1060),
+            Err(Refusal::Cooldown(Duration::from_secs(30)))
+        );
+        assert_eq!(attempts.check(1, "2", 1090), Ok(()));
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut attempts = Attempts::default();
+        attempts.record(2, "1234", &SubmitOutcome::TooLow, 1000);
+        let s = toml::to_string(&attempts).unwrap();
+        let loaded: Attempts = toml::from_str(&s).unwrap();
+        assert_eq!(loaded.wait_until, Some(1060));
+        assert_eq!(loaded.attempts, attempts.attempts);
+    }
+}
@@ -0,0 +1,89 @@
+//! Criterion benchmarks shared by every day. Needs the `bench` feature
+
+pub use criterion;
+use criterion::{black_box, BatchSize, Criterion};
+
+use crate::{Day, Parser, Result};
+
+/// Benchmarks parsing, each part and a whole run of a solution on `input`,
+/// grouped under the name of the challenge
+pub fn bench<C: Parser + Clone>(c: &mut Criterion, input: &'static str) {
+    benchmarks(
+        c,
+        C::NAME,
+        input,
+        crate::parse::<C>,
+        C::clone,
+        C::part_one,
+        C::part_two,
+    );
+}
+
+/// The same benchmarks as [`bench`] for a registered day, so that every day can be
+/// benchmarked from one place
+pub fn bench_day(c: &mut Criterion, day: &Day, input: &'static str) {
+    benchmarks(
+        c,
+        day.name,
+        input,
+        |input| day.parse(input),
+        |challenge| challenge.boxed_clone(),
+        |challenge| challenge.part_one(),
+        |challenge| challenge.part_two(),
+    );
+}
+
+fn benchmarks<T, O1, O2>(
+    c: &mut Criterion,
+    name: &str,
+    input: &'static str,
+    parse: impl Fn(&'static str) -> Result<T>,
+    clone: impl Fn(&T) -> T,
+    part_one: impl Fn(T) -> Result<O1>,
+    part_two: impl Fn(T) -> Result<O2>,
+) {
+    let challenge = match parse(input) {
+        Ok(challenge) => challenge,
+        Err(err) => {
+            eprintln!("skipping {name}: {err}");
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(name);
+
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+    group.bench_function("part1", |b| {
+        b.iter_batched(|| clone(&challenge), &part_one, BatchSize::SmallInput)
+    });
+    group.bench_function("part2", |b| {
+        b.iter_batched(|| clone(&challenge), &part_two, BatchSize::SmallInput)
+    });
+    group.bench_function("total", |b| {
+        b.iter(|| {
+            let challenge = parse(black_box(input)).ok()?;
+            Some((part_one(clone(&challenge)), part_two(challenge)))
+        })
+    });
+
+    group.finish();
+}
+
+/// Defines the criterion `main` for a day's benchmarks, run on its `input.txt`.
+/// Solutions with const parameters can use the type they were registered with.
+///
+/// ```ignore
+/// aoc::bench!(day15::Registered);
+/// ```
+#[macro_export]
+macro_rules! bench {
+    ($solution:ty) => {
+        fn bench(c: &mut $crate::bench::criterion::Criterion) {
+            let input = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+            $crate::bench::bench::<$solution>(c, input);
+        }
+
+        $crate::bench::criterion::criterion_group!(benches, bench);
+        $crate::bench::criterion::criterion_main!(benches);
+    };
+}
@@ -0,0 +1,81 @@
+use std::{fmt, io};
+
+use crate::YEAR;
+
+/// Talks to Advent of Code. Can be swapped out for a local stand-in in tests
+pub trait Client {
+    /// Sends an answer, returning the HTML body of the response
+    fn post_answer(&self, day: u32, level: u8, answer: &str) -> Result<String, HttpError>;
+
+    /// Fetches a page, eg `/2022/day/5/input`
+    fn get(&self, path: &str) -> Result<String, HttpError>;
+}
+
+/// Talks to adventofcode.com using [`ureq`]
+pub struct Ureq {
+    base_url: String,
+    session: String,
+}
+
+impl Ureq {
+    pub fn new(session: String) -> Self {
+        Self::with_base_url("https://adventofcode.com".to_owned(), session)
+    }
+
+    /// Sends requests to a different server, eg a local stand-in for tests
+    pub fn with_base_url(base_url: String, session: String) -> Self {
+        Self { base_url, session }
+    }
+
+    /// Reads the session cookie from `AOC_SESSION`
+    pub fn from_env() -> Result<Self, HttpError> {
+        let session = dotenv::var("AOC_SESSION").map_err(|_| HttpError::MissingSession)?;
+        Ok(Self::new(session))
+    }
+}
+
+impl Client for Ureq {
+    fn post_answer(&self, day: u32, level: u8, answer: &str) -> Result<String, HttpError> {
+        let url = format!("{}/{YEAR}/day/{day}/answer", self.base_url);
+        ureq::post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &level.to_string()), ("answer", answer)])
+            .map_err(|err| HttpError::Http(err.to_string()))?
+            .into_string()
+            .map_err(|err| HttpError::Http(err.to_string()))
+    }
+
+    fn get(&self, path: &str) -> Result<String, HttpError> {
+        ureq::get(&format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|err| HttpError::Http(err.to_string()))?
+            .into_string()
+            .map_err(|err| HttpError::Http(err.to_string()))
+    }
+}
+
+#[derive(Debug)]
+pub enum HttpError {
+    /// `AOC_SESSION` is not set
+    MissingSession,
+    /// The request failed or the response could not be read
+    Http(String),
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpError::MissingSession => f.write_str("AOC_SESSION is not set"),
+            HttpError::Http(err) => write!(f, "request failed: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+impl From<HttpError> for io::Error {
+    fn from(err: HttpError) -> Self {
+        io::Error::other(err)
+    }
+}
@@ -0,0 +1,456 @@
+use std::{io, path::Path};
+
+use crate::{Client, HttpError, YEAR};
+
+/// Downloads the puzzle description for the day and converts it to Markdown
+pub fn fetch(client: &dyn Client, day: u32) -> Result<String, HttpError> {
+    let html = client.get(&format!("/{YEAR}/day/{day}"))?;
+    Ok(to_markdown(&html))
+}
+
+/// Fetches the description and writes it to `readme`, eg once part two is unlocked.
+/// Returns whether the file changed
+pub fn update_readme(client: &dyn Client, day: u32, readme: &Path) -> io::Result<bool> {
+    let markdown = fetch(client, day)?;
+    match std::fs::read_to_string(readme) {
+        Ok(existing) if existing == markdown => Ok(false),
+        Ok(_) => std::fs::write(readme, markdown).map(|()| true),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            std::fs::write(readme, markdown).map(|()| true)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Converts the `.day-desc` articles of a puzzle page to Markdown.
+///
+/// `<em>` is rendered as bold, since that is how the site shows it, and highlighted numbers
+/// (`<code><em>1</em></code>`) are written as **`1`** rather than `**1**`
+pub fn to_markdown(html: &str) -> String {
+    let nodes = parse(html);
+    let mut articles = vec![];
+    find_articles(&nodes, &mut articles);
+
+    let mut md = String::new();
+    for article in articles {
+        let mut out = String::new();
+        block(article, &mut out);
+        md.push_str(out.trim_end());
+        md.push_str("\n\n");
+    }
+    highlight_numbers(&md)
+}
+
+#[derive(Debug)]
+enum Node {
+    Text(String),
+    Element {
+        name: String,
+        attrs: Vec<(String, String)>,
+        children: Vec<Node>,
+    },
+}
+
+impl Node {
+    fn attr(&self, key: &str) -> Option<&str> {
+        match self {
+            Node::Element { attrs, .. } => attrs
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str()),
+            Node::Text(_) => None,
+        }
+    }
+}
+
+fn find_articles<'a>(nodes: &'a [Node], articles: &mut Vec<&'a [Node]>) {
+    for node in nodes {
+        if let Node::Element { name, children, .. } = node {
+            let class = node.attr("class").unwrap_or_default();
+            if name == "article" && class.split_whitespace().any(|c| c == "day-desc") {
+                articles.push(children);
+            } else {
+                find_articles(children, articles);
+            }
+        }
+    }
+}
+
+/// A forgiving HTML parser, good enough for the pages served by Advent of Code.
+/// Unclosed elements are closed by their parent's closing tag
+fn parse(html: &str) -> Vec<Node> {
+    const VOID: &[&str] = &["br", "hr", "img", "input", "link", "meta"];
+
+    // the stack of open elements, with the root at the bottom
+    let mut stack: Vec<Open> = vec![(String::new(), vec![], vec![])];
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            stack.last_mut().unwrap().2.push(Node::Text(decode(rest)));
+            break;
+        };
+        if start > 0 {
+            stack
+                .last_mut()
+                .unwrap()
+                .2
+                .push(Node::Text(decode(&rest[..start])));
+        }
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.split_once("-->").map_or("", |(_, rest)| rest);
+            continue;
+        }
+        let Some(end) = tag_end(rest) else {
+            // a tag that is never closed is kept as text
+            stack.last_mut().unwrap().2.push(Node::Text(decode(rest)));
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = rest.get(end + 1..).unwrap_or_default();
+
+        if tag.starts_with('!') {
+            continue;
+        }
+        if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim().to_ascii_lowercase();
+            if let Some(depth) = stack.iter().rposition(|(open, ..)| *open == name) {
+                while stack.len() > depth.max(1) {
+                    close(&mut stack);
+                }
+            }
+            continue;
+        }
+
+        let self_closing = tag.ends_with('/');
+        let tag = tag.trim_end_matches('/');
+        let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+        let name = name.to_ascii_lowercase();
+        let attrs = parse_attrs(attrs);
+        if self_closing || VOID.contains(&name.as_str()) {
+            let element = Node::Element {
+                name,
+                attrs,
+                children: vec![],
+            };
+            stack.last_mut().unwrap().2.push(element);
+        } else {
+            stack.push((name, attrs, vec![]));
+        }
+    }
+
+    while stack.len() > 1 {
+        close(&mut stack);
+    }
+    stack.pop().unwrap().2
+}
+
+/// An element that has not been closed yet: its name, attributes and children so far
+type Open = (String, Vec<(String, String)>, Vec<Node>);
+
+/// Closes the innermost open element, adding it to its parent
+fn close(stack: &mut Vec<Open>) {
+    let (name, attrs, children) = stack.pop().unwrap();
+    let element = Node::Element {
+        name,
+        attrs,
+        children,
+    };
+    stack.last_mut().unwrap().2.push(element);
+}
+
+/// Finds the `>` that closes the tag at the start of `s`, skipping over quoted attributes.
+/// Returns `None` if the tag is not closed
+fn tag_end(s: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_attrs(mut s: &str) -> Vec<(String, String)> {
+    let mut attrs = vec![];
+    loop {
+        s = s.trim_start();
+        if s.is_empty() {
+            return attrs;
+        }
+        let key_end = s.find(['=', ' ', '\t', '\n']).unwrap_or(s.len());
+        let key = s[..key_end].to_ascii_lowercase();
+        s = s[key_end..].trim_start();
+        let value = match s.strip_prefix('=') {
+            None => String::new(),
+            Some(v) => {
+                let v = v.trim_start();
+                let (value, rest) = match v.chars().next() {
+                    Some(q @ ('"' | '\'')) => v[1..].split_once(q).unwrap_or((&v[1..], "")),
+                    _ => v.split_once(char::is_whitespace).unwrap_or((v, "")),
+                };
+                s = rest;
+                decode(value)
+            }
+        };
+        attrs.push((key, value));
+    }
+}
+
+fn decode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find(';').filter(|&end| end < 10) else {
+            out.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let c = match &rest[1..end] {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            entity => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+        match c {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Renders block level elements. Anything else is treated as a paragraph
+fn block(nodes: &[Node], out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) if text.trim().is_empty() => {}
+            Node::Element { name, children, .. } => match name.as_str() {
+                "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                    let level = name[1..].parse().unwrap_or(2);
+                    out.push_str(&"#".repeat(level));
+                    out.push(' ');
+                    paragraph(children, out);
+                }
+                "pre" => {
+                    out.push_str("```\n");
+                    text(children, out);
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("\n```\n\n");
+                }
+                "ul" | "ol" => {
+                    let items = children.iter().filter_map(|child| match child {
+                        Node::Element { name, children, .. } if name == "li" => Some(children),
+                        _ => None,
+                    });
+                    for (i, item) in items.enumerate() {
+                        if name == "ol" {
+                            out.push_str(&format!("{}. ", i + 1));
+                        } else {
+                            out.push_str("* ");
+                        }
+                        let mut line = String::new();
+                        inline(item, &mut line);
+                        out.push_str(line.trim());
+                        out.push('\n');
+                    }
+                    out.push('\n');
+                }
+                "p" | "div" => paragraph(children, out),
+                _ => paragraph(std::slice::from_ref(node), out),
+            },
+            Node::Text(_) => paragraph(std::slice::from_ref(node), out),
+        }
+    }
+}
+
+fn paragraph(nodes: &[Node], out: &mut String) {
+    let mut line = String::new();
+    inline(nodes, &mut line);
+    out.push_str(line.trim());
+    out.push_str("\n\n");
+}
+
+fn inline(nodes: &[Node], out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => escape(&collapse_whitespace(text, out), out),
+            Node::Element { name, children, .. } => match name.as_str() {
+                "em" | "strong" | "b" | "i" => {
+                    out.push_str("**");
+                    inline(children, out);
+                    out.push_str("**");
+                }
+                "code" => {
+                    out.push('`');
+                    code(children, out);
+                    out.push('`');
+                }
+                "a" => {
+                    out.push('[');
+                    inline(children, out);
+                    out.push_str("](");
+                    out.push_str(node.attr("href").unwrap_or_default());
+                    out.push(')');
+                }
+                "br" => out.push_str("  \n"),
+                _ => inline(children, out),
+            },
+        }
+    }
+}
+
+/// Inline code is not escaped, but emphasis inside of it is kept
+fn code(nodes: &[Node], out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Element { name, children, .. } if name == "em" => {
+                out.push_str("**");
+                code(children, out);
+                out.push_str("**");
+            }
+            Node::Element { children, .. } => code(children, out),
+        }
+    }
+}
+
+/// The text inside of a `<pre>`, as is
+fn text(nodes: &[Node], out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Element { children, .. } => text(children, out),
+        }
+    }
+}
+
+/// Collapses runs of whitespace into a single space, including across text that
+/// has already been written
+fn collapse_whitespace(text: &str, out: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut space = out.ends_with(' ') || out.is_empty();
+    for c in text.chars() {
+        if c.is_whitespace() && c != '\u{a0}' {
+            if !space {
+                collapsed.push(' ');
+            }
+            space = true;
+        } else {
+            collapsed.push(c);
+            space = false;
+        }
+    }
+    collapsed
+}
+
+/// Escapes characters that Markdown would otherwise treat as formatting
+fn escape(text: &str, out: &mut String) {
+    let start = text.len() - text.trim_start().len();
+    let mut chars = text.char_indices().peekable();
+    let mut prev = None;
+    while let Some((i, c)) = chars.next() {
+        let next = chars.peek().map(|&(_, c)| c);
+        let escaped = match c {
+            '\\' | '*' | '_' | '`' => true,
+            // would start a list, heading or quote
+            '-' | '+' | '#' | '>' => i == start,
+            // would start a numbered list
+            '.' => {
+                prev.is_some_and(|p: char| p.is_ascii_digit())
+                    && next.is_none_or(char::is_whitespace)
+            }
+            _ => false,
+        };
+        if escaped {
+            out.push('\\');
+        }
+        out.push(c);
+        prev = Some(c);
+    }
+}
+
+/// Turns `**1**` into **`1`**
+fn highlight_numbers(md: &str) -> String {
+    let mut out = String::with_capacity(md.len());
+    let mut rest = md;
+    while let Some(start) = rest.find("`**") {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let digits = rest[3..].len()
+            - rest[3..]
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .len();
+        if digits > 0 && rest[3 + digits..].starts_with("**`") {
+            out.push_str("**`");
+            out.push_str(&rest[3..3 + digits]);
+            out.push_str("`**");
+            rest = &rest[6 + digits..];
+        } else {
+            out.push('`');
+            rest = &rest[1..];
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_markdown;
+
+    #[test]
+    fn day01() {
+        let html = include_str!("../fixtures/day01.html");
+        let readme = include_str!("../../challenges/day01/README.md");
+        assert_eq!(to_markdown(html), readme);
+    }
+
+    #[test]
+    fn part_one_only() {
+        let html = include_str!("../fixtures/day01-part-one.html");
+        let readme = include_str!("../../challenges/day01/README.md");
+        let (part_one, _) = readme.split_once("## \\--- Part Two ---").unwrap();
+        assert_eq!(to_markdown(html), part_one);
+    }
+
+    #[test]
+    fn escapes() {
+        let html = r#"<article class="day-desc"><p>are <em>visible</em> - since 2 * 3 = 6. Crates in stack 3. <code>a*b</code>: <code><em>CMZ</em></code> and 1 - 2</p></article>"#;
+        assert_eq!(
+            to_markdown(html),
+            "are **visible** \\- since 2 \\* 3 = 6\\. Crates in stack 3\\. `a*b`: `**CMZ**` and 1 - 2\n\n"
+        );
+    }
+
+    #[test]
+    fn unclosed_tag() {
+        let html = r#"<article class="day-desc"><p>1 <"#;
+        assert_eq!(to_markdown(html), "1 <\n\n");
+        let html = r#"<article class="day-desc"><p>1 <é"#;
+        assert_eq!(to_markdown(html), "1 <é\n\n");
+    }
+}
@@ -0,0 +1,79 @@
+use std::fmt;
+
+use nom::error::ErrorKind;
+use parsers::diagnostic::Diagnostic;
+
+pub type Result<T, E = AocError> = std::result::Result<T, E>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    /// The input could not be parsed. `offset` is the byte offset into the input
+    /// where the parser gave up
+    Parse { offset: usize, kind: ErrorKind },
+    /// The input parsed fine but breaks an assumption the solution relies on
+    InvalidInput(&'static str),
+    /// There is no answer for this input
+    Unsolvable,
+}
+
+impl AocError {
+    /// Converts a nom error produced while parsing `input` into an [`AocError::Parse`].
+    ///
+    /// The offset is worked out from how much input the error holds, so the error must
+    /// hold the rest of `input` from where parsing failed, not a slice of one line
+    pub fn parse(input: &str, err: nom::Err<nom::error::Error<&str>>) -> Self {
+        match err {
+            nom::Err::Incomplete(_) => AocError::Parse {
+                offset: input.len(),
+                kind: ErrorKind::Eof,
+            },
+            nom::Err::Error(e) | nom::Err::Failure(e) => AocError::Parse {
+                offset: input.len().saturating_sub(e.input.len()),
+                kind: e.code,
+            },
+        }
+    }
+
+    /// Describes the error. Parse errors show the line of `input` where parsing failed
+    pub fn report(&self, input: &str) -> String {
+        match *self {
+            AocError::Parse { offset, kind } => Diagnostic::new(input, offset, kind).to_string(),
+            ref err => err.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Parse { offset, kind } => {
+                write!(f, "parse error at byte {offset}: {}", kind.description())
+            }
+            AocError::InvalidInput(reason) => write!(f, "invalid input: {reason}"),
+            AocError::Unsolvable => f.write_str("no solution found"),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use nom::error::ErrorKind;
+
+    use super::AocError;
+
+    #[test]
+    fn report() {
+        let input = "1000\n2000\nabc\n";
+        let err = AocError::Parse {
+            offset: 10,
+            kind: ErrorKind::Digit,
+        };
+        assert_eq!(
+            err.report(input),
+            "expected number at line 3, column 1\n  |\n3 | abc\n  | ^\n"
+        );
+        assert_eq!(AocError::Unsolvable.report(input), "no solution found");
+    }
+}
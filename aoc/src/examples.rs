@@ -0,0 +1,175 @@
+use serde::{Deserialize, Serialize};
+
+/// The examples from a day's description, stored in `challenges/<day>/examples.toml`
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq, Eq)]
+pub struct Examples {
+    #[serde(default, rename = "example")]
+    pub examples: Vec<Example>,
+}
+
+/// An example input and the answers it should give. Parts without an answer are not checked
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<String>,
+}
+
+impl Examples {
+    /// Pulls the examples out of a README written by [`crate::description`].
+    ///
+    /// The example input is the first code block of part one (or of its "larger example"),
+    /// and its answers are the last highlighted values of each part. Days that list several
+    /// small examples (`` * `input`: ... **`answer`** ``) get an example per item.
+    /// A larger example in part two becomes an example of its own.
+    ///
+    /// This is a guess, so the output should be checked by hand
+    pub fn from_readme(readme: &str) -> Self {
+        let (part_one, part_two) = readme
+            .split_once("## \\--- Part Two ---")
+            .unwrap_or((readme, ""));
+
+        let mut examples = vec![];
+        let (_, larger_one) = split_larger(part_one);
+        let (part_two, larger) = split_larger(part_two);
+
+        let input = code_blocks(larger_one)
+            .next()
+            .or_else(|| code_blocks(part_one).next());
+        if let Some(input) = input {
+            examples.push(Example {
+                input: input.to_owned(),
+                part_one: last_highlight(part_one),
+                part_two: last_highlight(part_two),
+            });
+        }
+        if let Some(input) = code_blocks(larger).next() {
+            examples.push(Example {
+                input: input.to_owned(),
+                part_one: None,
+                part_two: last_highlight(larger),
+            });
+        }
+
+        for (input, answer) in list_examples(part_one) {
+            examples.push(Example {
+                input: input.to_owned(),
+                part_one: Some(answer.to_owned()),
+                part_two: None,
+            });
+        }
+        for (input, answer) in list_examples(part_two) {
+            match examples.iter_mut().find(|e| e.input.trim_end() == input) {
+                Some(example) => example.part_two = Some(answer.to_owned()),
+                None => examples.push(Example {
+                    input: input.to_owned(),
+                    part_one: None,
+                    part_two: Some(answer.to_owned()),
+                }),
+            }
+        }
+
+        Self { examples }
+    }
+
+    pub fn to_toml(&self) -> String {
+        let toml = toml::to_string(self).unwrap_or_default();
+        format!("# Generated from README.md by `aoc examples`. Check the answers by hand\n\n{toml}")
+    }
+}
+
+/// Splits the description where it introduces a larger example, eg "Here's a larger example:"
+fn split_larger(md: &str) -> (&str, &str) {
+    ["larger example:", "larger program:"]
+        .into_iter()
+        .filter_map(|marker| md.find(marker))
+        .min()
+        .map_or((md, ""), |i| md.split_at(i))
+}
+
+/// The contents of each fenced code block
+fn code_blocks(md: &str) -> impl Iterator<Item = &str> {
+    md.split("```\n").skip(1).step_by(2).map(|block| {
+        // the converter leaves a blank line before the closing fence
+        block.strip_suffix('\n').unwrap_or(block)
+    })
+}
+
+/// Highlighted values: **`1`**, `**ABC**` or **1**
+fn highlights(md: &str) -> impl Iterator<Item = &str> {
+    let mut rest = md;
+    std::iter::from_fn(move || loop {
+        let start = rest.find(['*', '`'])?;
+        rest = &rest[start..];
+        for (open, close) in [("**`", "`**"), ("`**", "**`"), ("**", "**")] {
+            let Some(inner) = rest.strip_prefix(open) else {
+                continue;
+            };
+            let Some(end) = inner.find(close) else {
+                continue;
+            };
+            let value = &inner[..end];
+            let numeric = value.bytes().all(|b| b.is_ascii_digit());
+            if !value.is_empty() && (open != "**" || numeric) {
+                rest = &inner[end + close.len()..];
+                return Some(value);
+            }
+        }
+        rest = &rest[1..];
+    })
+}
+
+/// The last highlighted value outside of the listed examples
+fn last_highlight(md: &str) -> Option<String> {
+    md.lines()
+        .filter(|line| !line.starts_with("* `"))
+        .flat_map(highlights)
+        .last()
+        .map(str::to_owned)
+}
+
+/// List items like ``* `mjqjpqmgbljsphdztnvjfqwrcgsmlb`: first marker after character **`7`**``
+fn list_examples(md: &str) -> impl Iterator<Item = (&str, &str)> {
+    md.lines().filter_map(|line| {
+        let item = line.strip_prefix("* `")?;
+        let (input, rest) = item.split_once("`:")?;
+        Some((input, highlights(rest).last()?))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Example, Examples};
+
+    #[test]
+    fn day01() {
+        let readme = include_str!("../../challenges/day01/README.md");
+        let examples = Examples::from_readme(readme);
+        assert_eq!(
+            examples.examples,
+            [Example {
+                input: "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n"
+                    .to_owned(),
+                part_one: Some("24000".to_owned()),
+                part_two: Some("45000".to_owned()),
+            }]
+        );
+
+        let toml = examples.to_toml();
+        assert_eq!(toml::from_str::<Examples>(&toml).unwrap(), examples);
+    }
+
+    #[test]
+    fn day06() {
+        let readme = include_str!("../../challenges/day06/README.md");
+        let examples = Examples::from_readme(readme).examples;
+        assert_eq!(examples[0].input, "mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+        assert_eq!(examples[0].part_one.as_deref(), Some("7"));
+        assert_eq!(examples[0].part_two.as_deref(), Some("19"));
+        assert_eq!(examples[1].input, "bvwbjplbgvbhsrlpgdmjqwftvncz");
+        assert_eq!(examples[1].part_one.as_deref(), Some("5"));
+        assert_eq!(examples[1].part_two.as_deref(), Some("23"));
+    }
+}
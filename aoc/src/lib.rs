@@ -1,4 +1,32 @@
-use std::{fmt::Display, path::Path, time::Instant};
+use std::{
+    fmt::Display,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+pub use attempts::{Attempts, Refusal};
+pub use client::{Client, HttpError, Ureq};
+pub use error::{AocError, Result};
+pub use progress::Progress;
+pub use registry::{days, Day, DynChallenge};
+pub use submit::{submit, SubmitOutcome};
+
+#[doc(hidden)]
+pub use inventory as __inventory;
+
+mod attempts;
+#[cfg(feature = "bench")]
+pub mod bench;
+mod client;
+pub mod description;
+mod error;
+pub mod examples;
+mod progress;
+mod registry;
+pub mod scaffold;
+mod state;
+mod submit;
+pub mod testing;
 
 const YEAR: usize = 2022;
 
@@ -10,56 +38,182 @@ pub trait Challenge {
     const NAME: &'static str;
 
     type Output1: Display;
-    fn part_one(self) -> Self::Output1;
+    fn part_one(self) -> Result<Self::Output1>;
 
     type Output2: Display;
-    fn part_two(self) -> Self::Output2;
+    fn part_two(self) -> Result<Self::Output2>;
+}
+
+/// Parses the challenge input, turning any nom error into an [`AocError::Parse`]
+/// with the byte offset into `input` where parsing failed
+pub fn parse<C: Parser>(input: &'static str) -> Result<C> {
+    C::parse(input)
+        .map(|(_, challenge)| challenge)
+        .map_err(|err| AocError::parse(input, err))
+}
+
+/// Whether parsing took more than half of the total time of a run.
+///
+/// Some days do all of their work while parsing, which their part timings would otherwise hide
+pub fn parse_dominates(parse: Duration, total: Duration) -> bool {
+    parse > total / 2
 }
 
 pub fn check<C: Parser + Clone>(input: &'static str) {
     let start = Instant::now();
-    let challenge = C::parse(input).unwrap().1;
-    let p1 = challenge.clone().part_one();
+    let challenge = match parse::<C>(input) {
+        Ok(challenge) => challenge,
+        Err(err) => {
+            println!("{} failed to parse: {}", C::NAME, err.report(input));
+            return;
+        }
+    };
+    let parse = start.elapsed();
+
+    let clone = challenge.clone();
+    let start = Instant::now();
+    let p1 = clone.part_one();
+    let part_one = start.elapsed();
+
+    let start = Instant::now();
     let p2 = challenge.part_two();
-    println!("took: {:?}", start.elapsed());
+    let part_two = start.elapsed();
+
+    let total = parse + part_one + part_two;
+    println!("took: {total:?} (parse {parse:?}, part one {part_one:?}, part two {part_two:?})");
+    if parse_dominates(parse, total) {
+        println!("\tnote: most of the time is spent parsing");
+    }
+
+    match p1 {
+        Ok(p1) => println!("\tAnswer to part one: {p1}"),
+        Err(err) => println!("\t{} part one failed: {err}", C::NAME),
+    }
+    match p2 {
+        Ok(p2) => println!("\tAnswer to part two: {p2}"),
+        Err(err) => println!("\t{} part two failed: {err}", C::NAME),
+    }
+}
 
-    println!("\tAnswer to part one: {p1}");
-    println!("\tAnswer to part two: {p2}");
+/// The command line of a day's binary
+#[derive(clap::Parser)]
+struct RunArgs {
+    /// Run this part instead of the next unsolved one.
+    /// Its answer is only submitted if it is the next part to solve
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
 }
 
 pub fn run<C: Parser>(input: &'static str) {
     println!("\nRunning challenge {}", C::NAME);
 
-    let start = Instant::now();
-    let challenge = C::parse(input).unwrap().1;
+    let args = <RunArgs as clap::Parser>::parse();
+    let path = Progress::path(C::NAME);
+    let progress = Progress::load(&path).unwrap_or_else(|err| {
+        println!("\tCould not read {}: {err}", path.display());
+        None
+    });
+    let (part, should_submit) = progress::choose_part(args.part, progress.as_ref(), || {
+        progress::part_from_readme(C::NAME)
+    });
+    if !should_submit {
+        match progress.as_ref().and_then(Progress::next_part) {
+            Some(next) => println!("\tNot submitting, part {next} is the next part to solve"),
+            None => println!("\tNot submitting, both stars have already been earned"),
+        }
+    }
 
-    let file = Path::new("challenges").join(C::NAME).join("README.md");
-    let readme = std::fs::read_to_string(file).expect("could not read file");
-    let part_one = !readme.contains("--- Part Two ---");
+    let start = Instant::now();
+    let challenge = match parse::<C>(input) {
+        Ok(challenge) => challenge,
+        Err(err) => {
+            println!("{} failed to parse: {}", C::NAME, err.report(input));
+            return;
+        }
+    };
+    let parse = start.elapsed();
 
-    if part_one {
-        let p1 = challenge.part_one();
-        println!("took: {:?}", start.elapsed());
-        println!("\tAnswer to part one: {p1}. ({:?})", start.elapsed());
-        submit::<C, _>(1, p1);
+    let start = Instant::now();
+    if part == 1 {
+        match challenge.part_one() {
+            Ok(p1) => {
+                let took = start.elapsed();
+                println!("\tAnswer to part one: {p1}. (parse {parse:?}, part one {took:?})");
+                if should_submit {
+                    submit_answer::<C, _>(1, p1);
+                }
+            }
+            Err(err) => println!("\t{} part one failed: {err}", C::NAME),
+        }
     } else {
-        let p2 = challenge.part_two();
-        println!("\tAnswer to part two: {p2}. ({:?})", start.elapsed());
-        submit::<C, _>(2, p2);
+        match challenge.part_two() {
+            Ok(p2) => {
+                let took = start.elapsed();
+                println!("\tAnswer to part two: {p2}. (parse {parse:?}, part two {took:?})");
+                if should_submit {
+                    submit_answer::<C, _>(2, p2);
+                }
+            }
+            Err(err) => println!("\t{} part two failed: {err}", C::NAME),
+        }
     }
 }
 
-fn submit<C: Challenge, S: Display>(level: usize, answer: S) {
-    let session = dotenv::var("AOC_SESSION").unwrap();
+fn submit_answer<C: Challenge, S: Display>(level: u8, answer: S) {
+    let day = C::NAME[3..].parse::<u32>().unwrap();
+    let answer = answer.to_string();
 
-    let day = C::NAME[3..].parse::<i32>().unwrap();
-    let url = format!("https://adventofcode.com/{YEAR}/day/{day}/answer");
+    let path = Attempts::path(C::NAME);
+    let mut attempts = match Attempts::load(&path) {
+        Ok(attempts) => attempts,
+        Err(err) => {
+            println!("\tNot submitting, could not read {}: {err}", path.display());
+            return;
+        }
+    };
+    if let Err(refusal) = attempts.check(level, &answer, attempts::now()) {
+        println!("\tNot submitting: {refusal}");
+        return;
+    }
+
+    let outcome = Ureq::from_env().and_then(|client| submit(&client, day, level, &answer));
+    match outcome {
+        Ok(outcome) => {
+            println!("\tSubmitted: {outcome}");
+            attempts.record(level, &answer, &outcome, attempts::now());
+            if let Err(err) = attempts.save(&path) {
+                println!("\tCould not save {}: {err}", path.display());
+            }
 
-    ureq::post(&url)
-        .set("Cookie", &format!("session={session}"))
-        .send_form(&[
-            ("level", &format!("{level}")),
-            ("answer", &format!("{answer}")),
-        ])
-        .unwrap();
+            if outcome == SubmitOutcome::Correct && level == 1 {
+                update_readme::<C>(day);
+            }
+
+            let answer = match outcome {
+                SubmitOutcome::Correct => Some(answer.as_str()),
+                SubmitOutcome::AlreadySolved => None,
+                _ => return,
+            };
+            let path = Progress::path(C::NAME);
+            let mut progress = Progress::load(&path).ok().flatten().unwrap_or_default();
+            progress.solved(level, answer);
+            if let Err(err) = progress.save(&path) {
+                println!("\tCould not save {}: {err}", path.display());
+            }
+        }
+        Err(err) => println!("\tCould not submit: {err}"),
+    }
+}
+
+/// Part two is only described once part one is solved
+fn update_readme<C: Challenge>(day: u32) {
+    let readme = Path::new("challenges").join(C::NAME).join("README.md");
+    let updated = Ureq::from_env()
+        .map_err(Into::into)
+        .and_then(|client| description::update_readme(&client, day, &readme));
+    match updated {
+        Ok(true) => println!("\tUpdated {}", readme.display()),
+        Ok(false) => {}
+        Err(err) => println!("\tCould not update {}: {err}", readme.display()),
+    }
 }
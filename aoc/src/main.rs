@@ -0,0 +1,139 @@
+use std::{path::Path, process::ExitCode};
+
+use aoc::{description, examples::Examples, scaffold, Client, Ureq};
+use clap::{Parser, Subcommand};
+
+/// Tools for working on the Advent of Code 2022 solutions
+#[derive(Parser)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create challenges/<day> from the day00 template and add it to the all runner
+    New {
+        /// Day of the puzzle, from 1 to 25
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+
+        /// Download the puzzle input and description. Needs AOC_SESSION to be set
+        #[arg(long)]
+        fetch: bool,
+    },
+    /// Download the puzzle description into challenges/<day>/README.md
+    Readme {
+        /// Day of the puzzle, from 1 to 25
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+    },
+    /// Write the examples in challenges/<day>/README.md to examples.toml
+    Examples {
+        /// Day of the puzzle, from 1 to 25
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+    },
+}
+
+fn main() -> ExitCode {
+    match Args::parse().command {
+        Command::New { day, fetch } => new(day, fetch),
+        Command::Readme { day } => readme(day),
+        Command::Examples { day } => examples(day),
+    }
+}
+
+fn new(day: u32, fetch: bool) -> ExitCode {
+    let client = match fetch.then(Ureq::from_env).transpose() {
+        Ok(client) => client,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    match scaffold::new_day(&root, day, client.as_ref().map(|c| c as &dyn Client)) {
+        Ok(dir) => {
+            println!("created {}", dir.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn readme(day: u32) -> ExitCode {
+    let readme = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../challenges")
+        .join(format!("day{day:02}"))
+        .join("README.md");
+    let updated = Ureq::from_env()
+        .map_err(Into::into)
+        .and_then(|client| description::update_readme(&client, day, &readme));
+    match updated {
+        Ok(true) => println!("updated {}", readme.display()),
+        Ok(false) => println!("{} is up to date", readme.display()),
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn examples(day: u32) -> ExitCode {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../challenges")
+        .join(format!("day{day:02}"));
+    let readme = match std::fs::read_to_string(dir.join("README.md")) {
+        Ok(readme) => readme,
+        Err(err) => {
+            eprintln!("could not read the README: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let examples = Examples::from_readme(&readme);
+    let path = dir.join("examples.toml");
+    if let Err(err) = std::fs::write(&path, examples.to_toml()) {
+        eprintln!("{err}");
+        return ExitCode::FAILURE;
+    }
+    println!(
+        "wrote {} examples to {}",
+        examples.examples.len(),
+        path.display()
+    );
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::{CommandFactory, Parser};
+
+    use super::{Args, Command};
+
+    #[test]
+    fn commands() {
+        Args::command().debug_assert();
+
+        let parse = |args: &[&str]| Args::try_parse_from([&["aoc"], args].concat());
+        assert!(matches!(
+            parse(&["new", "5", "--fetch"]).unwrap().command,
+            Command::New {
+                day: 5,
+                fetch: true
+            }
+        ));
+        assert!(matches!(
+            parse(&["examples", "25"]).unwrap().command,
+            Command::Examples { day: 25 }
+        ));
+        assert!(parse(&["readme", "0"]).is_err());
+        assert!(parse(&["readme", "26"]).is_err());
+        assert!(parse(&["readme"]).is_err());
+    }
+}
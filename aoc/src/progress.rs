@@ -0,0 +1,130 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::state;
+
+/// The stars earned on a day and the answers that were accepted for them.
+///
+/// Stored in `challenges/<day>/progress.toml`
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq, Eq)]
+pub struct Progress {
+    pub stars: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<String>,
+}
+
+impl Progress {
+    pub fn path(day: &str) -> PathBuf {
+        Path::new("challenges").join(day).join("progress.toml")
+    }
+
+    /// Reads the progress from `path`, or `None` if no progress has been recorded yet
+    pub fn load(path: &Path) -> io::Result<Option<Self>> {
+        state::load(path)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        state::save(self, path)
+    }
+
+    /// Records that the level was solved. `answer` is `None` if the level was solved
+    /// but the answer is not known
+    pub fn solved(&mut self, level: u8, answer: Option<&str>) {
+        self.stars = self.stars.max(level);
+        let slot = match level {
+            1 => &mut self.part_one,
+            _ => &mut self.part_two,
+        };
+        if let Some(answer) = answer {
+            *slot = Some(answer.to_owned());
+        }
+    }
+
+    /// The part that should be solved next, or `None` if both stars have been earned
+    pub fn next_part(&self) -> Option<u8> {
+        match self.stars {
+            0 => Some(1),
+            1 => Some(2),
+            _ => None,
+        }
+    }
+}
+
+/// Picks which part to run and whether its answer should be submitted.
+///
+/// `part` is the part asked for on the command line. Otherwise it is the next part from the
+/// recorded progress, or `guess` if there is none. Only the next part is ever submitted,
+/// so an answer to a part that has already been solved is just printed
+pub(crate) fn choose_part(
+    part: Option<u8>,
+    progress: Option<&Progress>,
+    guess: impl FnOnce() -> u8,
+) -> (u8, bool) {
+    match (part, progress.map(Progress::next_part)) {
+        (Some(part), Some(next)) => (part, next == Some(part)),
+        (Some(part), None) => (part, true),
+        (None, Some(Some(next))) => (next, true),
+        (None, Some(None)) => (2, false),
+        (None, None) => (guess(), true),
+    }
+}
+
+/// Guesses which part is unlocked from the day's README.md, which only
+/// contains the part two description once part one has been solved.
+/// Guesses part one if there is no README
+pub fn part_from_readme(day: &str) -> u8 {
+    let file = Path::new("challenges").join(day).join("README.md");
+    match std::fs::read_to_string(file) {
+        Ok(readme) if readme.contains("--- Part Two ---") => 2,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{choose_part, Progress};
+
+    #[test]
+    fn next_part() {
+        let mut progress = Progress::default();
+        assert_eq!(progress.next_part(), Some(1));
+
+        progress.solved(1, Some("24000"));
+        assert_eq!(progress.next_part(), Some(2));
+
+        progress.solved(2, None);
+        assert_eq!(progress.next_part(), None);
+        assert_eq!(
+            progress,
+            Progress {
+                stars: 2,
+                part_one: Some("24000".to_owned()),
+                part_two: None,
+            }
+        );
+    }
+
+    #[test]
+    fn choosing_parts() {
+        let guess = || 1;
+        assert_eq!(choose_part(None, None, guess), (1, true));
+        assert_eq!(choose_part(Some(2), None, guess), (2, true));
+
+        let mut progress = Progress::default();
+        progress.solved(1, Some("24000"));
+        assert_eq!(choose_part(None, Some(&progress), guess), (2, true));
+        assert_eq!(choose_part(Some(2), Some(&progress), guess), (2, true));
+        assert_eq!(choose_part(Some(1), Some(&progress), guess), (1, false));
+
+        progress.solved(2, None);
+        assert_eq!(choose_part(None, Some(&progress), guess), (2, false));
+        assert_eq!(choose_part(Some(1), Some(&progress), guess), (1, false));
+        assert_eq!(choose_part(Some(2), Some(&progress), guess), (2, false));
+    }
+}
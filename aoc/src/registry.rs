@@ -0,0 +1,78 @@
+use crate::{Challenge, Parser, Result};
+
+/// A parsed challenge with its types erased, so that every day can be driven
+/// through the same interface. It is `Send` so that the parts can run on different threads
+pub trait DynChallenge: Send {
+    fn part_one(self: Box<Self>) -> Result<String>;
+    fn part_two(self: Box<Self>) -> Result<String>;
+    fn boxed_clone(&self) -> Box<dyn DynChallenge>;
+}
+
+impl<C: Challenge + Clone + Send + 'static> DynChallenge for C {
+    fn part_one(self: Box<Self>) -> Result<String> {
+        Challenge::part_one(*self).map(|p| p.to_string())
+    }
+
+    fn part_two(self: Box<Self>) -> Result<String> {
+        Challenge::part_two(*self).map(|p| p.to_string())
+    }
+
+    fn boxed_clone(&self) -> Box<dyn DynChallenge> {
+        Box::new(self.clone())
+    }
+}
+
+/// A solution registered with [`register!`](crate::register)
+pub struct Day {
+    pub name: &'static str,
+    parse: fn(&'static str) -> Result<Box<dyn DynChallenge>>,
+}
+
+impl Day {
+    pub const fn new<C: Parser + Clone + Send + 'static>() -> Self {
+        Self {
+            name: C::NAME,
+            parse: parse_dyn::<C>,
+        }
+    }
+
+    pub fn parse(&self, input: &'static str) -> Result<Box<dyn DynChallenge>> {
+        (self.parse)(input)
+    }
+}
+
+fn parse_dyn<C: Parser + Clone + Send + 'static>(
+    input: &'static str,
+) -> Result<Box<dyn DynChallenge>> {
+    Ok(Box::new(crate::parse::<C>(input)?))
+}
+
+inventory::collect!(Day);
+
+/// All registered days, in order.
+///
+/// Only days from crates that are linked into the final binary are included.
+pub fn days() -> Vec<&'static Day> {
+    let mut days: Vec<_> = inventory::iter::<Day>.into_iter().collect();
+    days.sort_by_key(|day| day.name);
+    days
+}
+
+/// Registers a solution so that it can be found with [`days`](crate::days).
+/// Any const parameters the solution needs for the real input should be given here.
+///
+/// It also defines `Registered` as the registered type, for the day's own
+/// binary and benchmarks to use.
+///
+/// ```ignore
+/// aoc::register!(Solution<4000000>);
+/// ```
+#[macro_export]
+macro_rules! register {
+    ($solution:ty) => {
+        /// The solution with the parameters for the real input
+        pub type Registered = $solution;
+
+        $crate::__inventory::submit! { $crate::Day::new::<$solution>() }
+    };
+}
@@ -0,0 +1,255 @@
+use std::{
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{description, examples::Examples, Client, HttpError, YEAR};
+
+/// Creates `challenges/dayNN` from the `day00` template and adds it to the dependencies
+/// of the `all` runner, which links in every day it depends on.
+///
+/// If a client is given, the puzzle input and description are downloaded too,
+/// along with the examples from the description,
+/// otherwise `input.txt` is left empty and there is no README.
+/// `root` is the root of the workspace
+pub fn new_day(root: &Path, day: u32, client: Option<&dyn Client>) -> Result<PathBuf, NewDayError> {
+    let name = format!("day{day:02}");
+    let dir = root.join("challenges").join(&name);
+    if dir.exists() {
+        return Err(NewDayError::Exists(dir));
+    }
+
+    // fetch first so that a failed download doesn't leave a half made day behind
+    let (input, readme) = match client {
+        Some(client) => (
+            client.get(&format!("/{YEAR}/day/{day}/input"))?,
+            Some(description::fetch(client, day)?),
+        ),
+        None => (String::new(), None),
+    };
+
+    copy_template(&root.join("challenges/day00"), &dir, &name)?;
+    std::fs::write(dir.join("input.txt"), input)?;
+    if let Some(readme) = readme {
+        std::fs::write(dir.join("README.md"), &readme)?;
+        let examples = Examples::from_readme(&readme);
+        std::fs::write(dir.join("examples.toml"), examples.to_toml())?;
+    }
+
+    add_workspace_member(&root.join("Cargo.toml"), &name)?;
+    add_line(
+        &root.join("all/Cargo.toml"),
+        day,
+        &format!("{name} = {{ path = \"../challenges/{name}\" }}"),
+        "# ",
+    )?;
+
+    Ok(dir)
+}
+
+#[derive(Debug)]
+pub enum NewDayError {
+    /// The day has already been created
+    Exists(PathBuf),
+    Io(io::Error),
+    Http(HttpError),
+}
+
+impl From<io::Error> for NewDayError {
+    fn from(err: io::Error) -> Self {
+        NewDayError::Io(err)
+    }
+}
+
+impl From<HttpError> for NewDayError {
+    fn from(err: HttpError) -> Self {
+        NewDayError::Http(err)
+    }
+}
+
+impl fmt::Display for NewDayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NewDayError::Exists(dir) => write!(f, "{} already exists", dir.display()),
+            NewDayError::Io(err) => write!(f, "{err}"),
+            NewDayError::Http(err) => write!(f, "could not fetch the puzzle: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for NewDayError {}
+
+/// Copies the template, renaming `day00` to `name`. The template's input is not copied
+fn copy_template(from: &Path, to: &Path, name: &str) -> io::Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let file_name = entry.file_name();
+        let path = to.join(&file_name);
+        if file_name == "target" || (file_name == "input.txt" && from.ends_with("day00")) {
+            continue;
+        }
+        if entry.file_type()?.is_dir() {
+            copy_template(&entry.path(), &path, name)?;
+        } else {
+            let contents = std::fs::read_to_string(entry.path())?;
+            std::fs::write(path, rename(&contents, name))?;
+        }
+    }
+    Ok(())
+}
+
+/// Replaces `day00` with `name`, keeping the case of the `day` prefix
+fn rename(contents: &str, name: &str) -> String {
+    let number = &name[3..];
+    ["day", "Day", "DAY"]
+        .into_iter()
+        .fold(contents.to_owned(), |contents, prefix| {
+            contents.replace(&format!("{prefix}00"), &format!("{prefix}{number}"))
+        })
+}
+
+/// Makes sure the workspace includes the new day. Nothing needs to change if the
+/// members already contain a glob such as `challenges/*`
+fn add_workspace_member(manifest: &Path, name: &str) -> io::Result<()> {
+    let contents = std::fs::read_to_string(manifest)?;
+    let member = format!("\"challenges/{name}\"");
+    if contents.contains("\"challenges/*\"") || contents.contains(&member) {
+        return Ok(());
+    }
+    let Some(start) = contents.find("members = [") else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "could not find the workspace members",
+        ));
+    };
+    let insert = start + contents[start..].find(']').unwrap_or_default();
+    let contents = format!("{}, {member}{}", &contents[..insert], &contents[insert..]);
+    std::fs::write(manifest, contents)
+}
+
+/// Adds `line` to a file that has a line per day, eg the dependencies of the `all` runner.
+///
+/// It goes after the lines of the earlier days (or before the first day), or replaces the line if it was commented out
+/// with `comment`
+fn add_line(path: &Path, day: u32, line: &str, comment: &str) -> io::Result<()> {
+    let contents = std::fs::read_to_string(path)?;
+    let mut lines: Vec<&str> = contents.lines().collect();
+
+    let commented = format!("{comment}{line}");
+    if let Some(existing) = lines.iter_mut().find(|l| **l == line || **l == commented) {
+        *existing = line;
+    } else {
+        let day_of = |l: &str| {
+            let l = l.strip_prefix(comment).unwrap_or(l);
+            l.strip_prefix("day")?.get(..2)?.parse::<u32>().ok()
+        };
+        let days = || lines.iter().map(|l| day_of(l));
+        let at = match days().rposition(|d| d.is_some_and(|d| d < day)) {
+            Some(earlier) => earlier + 1,
+            None => days().position(|d| d.is_some()).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("could not find where to add the day in {}", path.display()),
+                )
+            })?,
+        };
+        lines.insert(at, line);
+    }
+
+    let mut contents = lines.join("\n");
+    contents.push('\n');
+    std::fs::write(path, contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{new_day, NewDayError};
+    use crate::{Client, HttpError};
+
+    struct Input;
+
+    impl Client for Input {
+        fn post_answer(&self, _: u32, _: u8, _: &str) -> Result<String, HttpError> {
+            Err(HttpError::Http("scaffolding never submits".to_owned()))
+        }
+
+        fn get(&self, path: &str) -> Result<String, HttpError> {
+            match path {
+                "/2022/day/7/input" => Ok("$ cd /\n".to_owned()),
+                "/2022/day/7" => Ok(
+                    "<article class=\"day-desc\"><h2>--- Day 7: No Space Left On Device ---</h2></article>"
+                        .to_owned(),
+                ),
+                _ => panic!("unexpected request for {path}"),
+            }
+        }
+    }
+
+    fn write(path: &Path, contents: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+
+    fn workspace() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        let root_path = root.path();
+        write(
+            &root_path.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"aoc\", \"challenges/day01\", \"challenges/day08\"]\n",
+        );
+        write(
+            &root_path.join("challenges/day00/Cargo.toml"),
+            "[package]\nname = \"day00\"\n",
+        );
+        write(
+            &root_path.join("challenges/day00/src/main.rs"),
+            "use day00::Solution;\n",
+        );
+        write(&root_path.join("challenges/day00/input.txt"), "template");
+        write(
+            &root_path.join("all/Cargo.toml"),
+            "[dependencies]\naoc = { path = \"../aoc\" }\nday01 = { path = \"../challenges/day01\" }\n# day07 = { path = \"../challenges/day07\" }\nday08 = { path = \"../challenges/day08\" }\n",
+        );
+        root
+    }
+
+    #[test]
+    fn scaffold() {
+        let root = workspace();
+        let root = root.path();
+
+        let dir = new_day(root, 7, Some(&Input)).unwrap();
+        assert_eq!(dir, root.join("challenges/day07"));
+
+        let read = |path: &str| std::fs::read_to_string(root.join(path)).unwrap();
+        assert_eq!(
+            read("challenges/day07/Cargo.toml"),
+            "[package]\nname = \"day07\"\n"
+        );
+        assert_eq!(
+            read("challenges/day07/src/main.rs"),
+            "use day07::Solution;\n"
+        );
+        assert_eq!(read("challenges/day07/input.txt"), "$ cd /\n");
+        assert_eq!(
+            read("challenges/day07/README.md"),
+            "## \\--- Day 7: No Space Left On Device ---\n\n"
+        );
+        assert_eq!(
+            read("Cargo.toml"),
+            "[workspace]\nmembers = [\"aoc\", \"challenges/day01\", \"challenges/day08\", \"challenges/day07\"]\n"
+        );
+        assert_eq!(
+            read("all/Cargo.toml"),
+            "[dependencies]\naoc = { path = \"../aoc\" }\nday01 = { path = \"../challenges/day01\" }\nday07 = { path = \"../challenges/day07\" }\nday08 = { path = \"../challenges/day08\" }\n"
+        );
+
+        assert!(matches!(
+            new_day(root, 7, None),
+            Err(NewDayError::Exists(_))
+        ));
+    }
+}
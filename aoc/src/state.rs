@@ -0,0 +1,48 @@
+//! The TOML files in each day's folder that record what happened on earlier runs
+
+use std::{io, path::Path};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+fn invalid(err: impl std::error::Error + Send + Sync + 'static) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+/// Reads a value from `path`, or `None` if the file does not exist yet
+pub(crate) fn load<T: DeserializeOwned>(path: &Path) -> io::Result<Option<T>> {
+    match std::fs::read_to_string(path) {
+        Ok(s) => toml::from_str(&s).map(Some).map_err(invalid),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+pub(crate) fn save<T: Serialize>(value: &T, path: &Path) -> io::Result<()> {
+    std::fs::write(path, toml::to_string(value).map_err(invalid)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crate::Progress;
+
+    #[test]
+    fn load_and_save() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("progress.toml");
+        assert_eq!(super::load::<Progress>(&path).unwrap(), None);
+
+        let progress = Progress {
+            stars: 1,
+            part_one: Some("24000".to_owned()),
+            part_two: None,
+        };
+        super::save(&progress, &path).unwrap();
+        assert_eq!(super::load(&path).unwrap(), Some(progress));
+
+        std::fs::write(&path, "stars = \"one\"").unwrap();
+        let err = super::load::<Progress>(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,201 @@
+use std::{fmt, time::Duration};
+
+use crate::{Client, HttpError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// An answer was submitted too recently
+    Wait(Duration),
+    /// The part has already been solved, or is not unlocked yet
+    AlreadySolved,
+    /// The response was not recognised. Contains the text of the response
+    Unknown(String),
+}
+
+impl SubmitOutcome {
+    /// Reads the outcome from the HTML page returned after submitting an answer
+    pub fn from_html(html: &str) -> Self {
+        let text = article_text(html);
+        if text.contains("That's the right answer") {
+            SubmitOutcome::Correct
+        } else if text.contains("your answer is too high") {
+            SubmitOutcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            SubmitOutcome::TooLow
+        } else if text.contains("That's not the right answer") {
+            SubmitOutcome::Wrong
+        } else if text.contains("You gave an answer too recently") {
+            SubmitOutcome::Wait(left_to_wait(&text).unwrap_or_default())
+        } else if text.contains("Did you already complete it?") {
+            SubmitOutcome::AlreadySolved
+        } else {
+            SubmitOutcome::Unknown(text)
+        }
+    }
+
+    /// How long to wait before submitting another answer.
+    ///
+    /// The server asks for at least a minute after a wrong answer. It asks for longer after
+    /// several wrong answers, but answering early will then give a [`SubmitOutcome::Wait`]
+    pub fn cooldown(&self) -> Duration {
+        match self {
+            SubmitOutcome::Wait(d) => *d,
+            SubmitOutcome::TooHigh | SubmitOutcome::TooLow | SubmitOutcome::Wrong => {
+                Duration::from_secs(60)
+            }
+            SubmitOutcome::Correct | SubmitOutcome::AlreadySolved | SubmitOutcome::Unknown(_) => {
+                Duration::ZERO
+            }
+        }
+    }
+}
+
+impl fmt::Display for SubmitOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitOutcome::Correct => f.write_str("correct"),
+            SubmitOutcome::TooHigh => f.write_str("wrong, too high"),
+            SubmitOutcome::TooLow => f.write_str("wrong, too low"),
+            SubmitOutcome::Wrong => f.write_str("wrong"),
+            SubmitOutcome::Wait(d) => write!(f, "answered too recently, wait {d:?}"),
+            SubmitOutcome::AlreadySolved => f.write_str("already solved"),
+            SubmitOutcome::Unknown(text) => write!(f, "unknown response: {text}"),
+        }
+    }
+}
+
+/// Submits the answer to the given part of the day
+pub fn submit(
+    client: &dyn Client,
+    day: u32,
+    level: u8,
+    answer: &str,
+) -> Result<SubmitOutcome, HttpError> {
+    let html = client.post_answer(day, level, answer)?;
+    Ok(SubmitOutcome::from_html(&html))
+}
+
+/// The text content of the `<article>` in the page, or the whole page if there isn't one
+fn article_text(html: &str) -> String {
+    // starts inside of the `<article ...>` tag
+    let (article, mut in_tag) = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or((html, false), |(article, _)| (article, true));
+
+    let mut text = String::new();
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses "You have 1m 30s left to wait"
+fn left_to_wait(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (time, _) = rest.split_once(" left to wait")?;
+    time.split_whitespace()
+        .try_fold(Duration::ZERO, |total, part| {
+            let secs = match part.split_at(part.len().checked_sub(1)?) {
+                (n, "h") => n.parse::<u64>().ok()? * 3600,
+                (n, "m") => n.parse::<u64>().ok()? * 60,
+                (n, "s") => n.parse::<u64>().ok()?,
+                _ => return None,
+            };
+            Some(total + Duration::from_secs(secs))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        thread::{self, JoinHandle},
+        time::Duration,
+    };
+
+    use super::{submit, SubmitOutcome};
+    use crate::Ureq;
+
+    fn page(article: &str) -> String {
+        format!(
+            "<!DOCTYPE html><html><body><main><article><p>{article}</p></article></main></body></html>"
+        )
+    }
+
+    /// Serves a single request with `body`, sending back the url and form it received
+    fn mock_server(body: String) -> (String, JoinHandle<(String, String)>) {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            let mut request = server.recv().unwrap();
+            let mut form = String::new();
+            request.as_reader().read_to_string(&mut form).unwrap();
+            let url = request.url().to_owned();
+            let cookie = request
+                .headers()
+                .iter()
+                .find(|h| h.field.equiv("Cookie"))
+                .map(|h| h.value.to_string())
+                .unwrap_or_default();
+            assert_eq!(cookie, "session=secret");
+            request
+                .respond(tiny_http::Response::from_string(body))
+                .unwrap();
+            (url, form)
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn submit_to_mock_server() {
+        let (url, server) = mock_server(page(
+            "That's the right answer! You are one gold star closer to saving your vacation.",
+        ));
+        let client = Ureq::with_base_url(url, "secret".to_owned());
+
+        let outcome = submit(&client, 5, 2, "PWPWHGFZS").unwrap();
+        assert_eq!(outcome, SubmitOutcome::Correct);
+
+        let (url, form) = server.join().unwrap();
+        assert_eq!(url, "/2022/day/5/answer");
+        assert_eq!(form, "level=2&answer=PWPWHGFZS");
+    }
+
+    #[test]
+    fn outcomes() {
+        let cases = [
+            (
+                "That's not the right answer; your answer is too high. <a href=\"/2022/day/1\">[Return to Day 1]</a>",
+                SubmitOutcome::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                SubmitOutcome::TooLow,
+            ),
+            (
+                "That's not the right answer. If you're stuck, make sure you're using the full input data",
+                SubmitOutcome::Wrong,
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 10s left to wait.",
+                SubmitOutcome::Wait(Duration::from_secs(250)),
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                SubmitOutcome::AlreadySolved,
+            ),
+        ];
+        for (article, outcome) in cases {
+            assert_eq!(SubmitOutcome::from_html(&page(article)), outcome);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Helpers for the tests generated by [`test_challenge!`](crate::test_challenge)
+
+use std::fmt::Display;
+
+use crate::{examples::Examples, AocError, Parser};
+
+/// An input and the answers it should give. Parts without an answer are not checked
+pub struct Case {
+    pub input: &'static str,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Case {
+    pub fn new(input: &'static str, part_one: Option<String>, part_two: Option<String>) -> Self {
+        Self {
+            input,
+            part_one,
+            part_two,
+        }
+    }
+}
+
+/// The examples from a day's `examples.toml`, see [`Examples`]
+pub fn examples(toml: &str) -> Vec<Case> {
+    let examples: Examples = toml::from_str(toml).expect("examples should be valid toml");
+    examples
+        .examples
+        .into_iter()
+        .map(|example| {
+            let input = Box::leak(example.input.into_boxed_str());
+            Case::new(input, example.part_one, example.part_two)
+        })
+        .collect()
+}
+
+/// Parses `input`, panicking if the parser fails or leaves any input behind
+pub fn parse<C: Parser>(input: &'static str) -> C {
+    match C::parse(input) {
+        Ok(("", challenge)) => challenge,
+        Ok((rest, _)) => {
+            let offset = input.len() - rest.len();
+            let rest: String = rest.chars().take(40).collect();
+            panic!("parse stopped at byte {offset}, leaving {rest:?}")
+        }
+        Err(err) => panic!("{}", AocError::parse(input, err).report(input)),
+    }
+}
+
+/// Checks that every case parses completely
+pub fn check_parse<C: Parser>(cases: &[Case]) {
+    for case in cases {
+        parse::<C>(case.input);
+    }
+}
+
+pub fn check_part_one<C: Parser>(cases: &[Case]) {
+    check(cases, |case| &case.part_one, |c: C| answer(c.part_one()));
+}
+
+pub fn check_part_two<C: Parser>(cases: &[Case]) {
+    check(cases, |case| &case.part_two, |c: C| answer(c.part_two()));
+}
+
+fn check<C: Parser>(
+    cases: &[Case],
+    expected: impl Fn(&Case) -> &Option<String>,
+    solve: impl Fn(C) -> String,
+) {
+    let mut failures = vec![];
+    for (i, case) in cases.iter().enumerate() {
+        let Some(expected) = expected(case) else {
+            continue;
+        };
+        let actual = solve(parse::<C>(case.input));
+        if *expected != actual {
+            failures.push(format!("case {i}: expected {expected}, got {actual}"));
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+fn answer(answer: crate::Result<impl Display>) -> String {
+    match answer {
+        Ok(answer) => answer.to_string(),
+        Err(err) => format!("error: {err}"),
+    }
+}
+
+/// Generates `parse`, `part_one` and `part_two` tests for a solution, run on the
+/// examples in the day's `examples.toml`.
+///
+/// Inputs that aren't in the puzzle description can be given as extra cases, each an input
+/// followed by the answers to each part, compared by their [`Display`] output. `_` skips a part.
+/// The parse test fails if any input is left unparsed
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     use super::Solution;
+///
+///     aoc::test_challenge! {
+///         Solution;
+///         ("R 4\nU 4\n", 13, 1),
+///         ("R 5\nU 8\n", _, 36),
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! test_challenge {
+    (@answer _) => { None };
+    (@answer $answer:expr) => { Some(::std::string::ToString::to_string(&$answer)) };
+    (@case $input:expr, $one:tt, $two:tt) => {
+        $crate::testing::Case::new(
+            $input,
+            $crate::test_challenge!(@answer $one),
+            $crate::test_challenge!(@answer $two),
+        )
+    };
+    ($solution:ty $(; $(($input:expr, $one:tt, $two:tt)),+ $(,)?)?) => {
+        fn cases() -> ::std::vec::Vec<$crate::testing::Case> {
+            #[allow(unused_mut)]
+            let mut cases = $crate::testing::examples(include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/examples.toml"
+            )));
+            $($(cases.push($crate::test_challenge!(@case $input, $one, $two));)+)?
+            cases
+        }
+
+        #[test]
+        fn parse() {
+            $crate::testing::check_parse::<$solution>(&cases());
+        }
+
+        #[test]
+        fn part_one() {
+            $crate::testing::check_part_one::<$solution>(&cases());
+        }
+
+        #[test]
+        fn part_two() {
+            $crate::testing::check_part_two::<$solution>(&cases());
+        }
+    };
+}
@@ -14,38 +14,21 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = usize;
-    fn part_one(self) -> Self::Output1 {
-        0
+    fn part_one(self) -> aoc::Result<Self::Output1> {
+        Ok(0)
     }
 
     type Output2 = usize;
-    fn part_two(self) -> Self::Output2 {
-        0
+    fn part_two(self) -> aoc::Result<Self::Output2> {
+        Ok(0)
     }
 }
 
+aoc::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "";
-
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
 
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 0);
-    }
-
-    #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 0);
-    }
+    aoc::test_challenge!(Solution);
 }
@@ -1,40 +1,43 @@
 use std::cmp;
 
-use aoc::{Challenge, Parser as ChallengeParser};
-use nom::{character::complete::line_ending, IResult, Parser};
+use aoc::{AocError, Challenge, Parser as ChallengeParser};
+use nom::{character::complete::line_ending, error::Error, IResult};
 use parsers::{number, ParserExt};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Solution(Vec<usize>);
 
-#[derive(Default)]
-struct Sum(usize);
-
-impl Extend<usize> for Sum {
-    fn extend<T: IntoIterator<Item = usize>>(&mut self, iter: T) {
-        for i in iter {
-            self.0 += i;
-        }
-    }
-}
-
 impl ChallengeParser for Solution {
-    fn parse(input: &'static str) -> IResult<&'static str, Self> {
-        number::<usize> // numbers
-            .terminate_list1(line_ending) // terminated by new lines
-            .map(|Sum(s)| s) // which are summed together
-            .separated_list1(line_ending) // number groups are separated by more lines
-            .map(Self)
-            .parse(input)
+    fn parse(mut input: &'static str) -> IResult<&'static str, Self> {
+        let mut sums = vec![];
+        loop {
+            // numbers on each line, which are summed together
+            let mut numbers = number::<usize>.iter_lines(input);
+            sums.push(numbers.by_ref().sum());
+            input = numbers.finish()?;
+
+            // number groups are separated by more lines
+            match line_ending::<_, Error<_>>(input) {
+                Ok((rest, _)) => input = rest,
+                Err(_) => break,
+            }
+        }
+        Ok((input, Self(sums)))
     }
 }
 
 impl Solution {
-    fn solve(mut self, n: usize) -> Vec<usize> {
+    /// The `n` largest sums
+    fn solve(mut self, n: usize) -> aoc::Result<Vec<usize>> {
+        if self.0.len() < n {
+            return Err(AocError::InvalidInput(
+                "there are not enough groups of numbers",
+            ));
+        }
         self.0
             .select_nth_unstable_by_key(n - 1, |x| cmp::Reverse(*x));
         self.0.truncate(n);
-        self.0
+        Ok(self.0)
     }
 }
 
@@ -42,52 +45,37 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = usize;
-    fn part_one(self) -> Self::Output1 {
-        self.solve(1).swap_remove(0)
+    fn part_one(self) -> aoc::Result<Self::Output1> {
+        Ok(self.solve(1)?.swap_remove(0))
     }
 
     type Output2 = usize;
-    fn part_two(self) -> Self::Output2 {
-        self.solve(3).into_iter().sum()
+    fn part_two(self) -> aoc::Result<Self::Output2> {
+        Ok(self.solve(3)?.into_iter().sum())
     }
 }
 
+aoc::register!(Solution);
+
 #[cfg(test)]
 mod tests {
-    use super::Solution;
-    use aoc::{Challenge, Parser};
+    use aoc::{AocError, Challenge};
 
-    const INPUT: &str = "1000
-2000
-3000
-
-4000
-
-5000
-6000
-
-7000
-8000
-9000
-
-10000
-";
-
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
+    use super::Solution;
 
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 24000);
-    }
+    aoc::test_challenge!(Solution);
 
     #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 45000);
+    fn empty_groups() {
+        assert!(aoc::parse::<Solution>("").is_err());
+        assert!(aoc::parse::<Solution>("1\n\n\n2\n").is_err());
+        assert!(aoc::parse::<Solution>("1\n\n").is_err());
+
+        let solution = aoc::parse::<Solution>("1\n\n2\n").unwrap();
+        assert_eq!(solution.clone().part_one(), Ok(2));
+        assert!(matches!(
+            solution.part_two(),
+            Err(AocError::InvalidInput(_))
+        ));
     }
 }
@@ -1,48 +1,29 @@
 use aoc::{Challenge, Parser as ChallengeParser};
-use nom::{
-    branch::alt, bytes::complete::tag, character::complete::line_ending, sequence::separated_pair,
-    IResult, Parser,
-};
-use parsers::ParserExt;
+use nom::{bytes::complete::tag, sequence::separated_pair, IResult, Parser};
+use parsers::{AocParse, ParserExt};
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, AocParse)]
 #[repr(usize)]
 enum Rps {
+    #[tag("A")]
     R = 1,
+    #[tag("B")]
     P = 2,
+    #[tag("C")]
     S = 3,
 }
 
-impl Rps {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        alt((
-            tag("A").map(|_| Rps::R),
-            tag("B").map(|_| Rps::P),
-            tag("C").map(|_| Rps::S),
-        ))
-        .parse(input)
-    }
-}
-
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, AocParse)]
 #[repr(usize)]
 enum Goal {
+    #[tag("X")]
     Lose = 0,
+    #[tag("Y")]
     Draw = 3,
+    #[tag("Z")]
     Win = 6,
 }
 
-impl Goal {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        alt((
-            tag("X").map(|_| Goal::Lose),
-            tag("Y").map(|_| Goal::Draw),
-            tag("Z").map(|_| Goal::Win),
-        ))
-        .parse(input)
-    }
-}
-
 impl Goal {
     fn part1(self) -> Rps {
         match self {
@@ -69,7 +50,7 @@ pub struct Solution(Vec<(Rps, Goal)>);
 impl ChallengeParser for Solution {
     fn parse(input: &'static str) -> IResult<&'static str, Self> {
         separated_pair(Rps::parse, tag(" "), Goal::parse)
-            .separated_list1(line_ending)
+            .lines()
             .map(Self)
             .parse(input)
     }
@@ -79,47 +60,29 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = usize;
-    fn part_one(self) -> Self::Output1 {
-        self.0
+    fn part_one(self) -> aoc::Result<Self::Output1> {
+        Ok(self
+            .0
             .into_iter()
             .map(|(against, player)| player.part1().score_against(against))
-            .sum()
+            .sum())
     }
 
     type Output2 = usize;
-    fn part_two(self) -> Self::Output2 {
-        self.0
+    fn part_two(self) -> aoc::Result<Self::Output2> {
+        Ok(self
+            .0
             .into_iter()
             .map(|(against, goal)| goal.score_against(against))
-            .sum()
+            .sum())
     }
 }
 
+aoc::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "A Y
-B X
-C Z
-";
-
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
 
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 15);
-    }
-
-    #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 12);
-    }
+    aoc::test_challenge!(Solution);
 }
@@ -10,7 +10,7 @@ pub struct Solution(ArrayVec<(usize, usize), 300>);
 impl ChallengeParser for Solution {
     fn parse(input: &'static str) -> IResult<&'static str, Self> {
         let mut lines = ArrayVec::new();
-        for slice in input.as_bytes().split(|&x| x == b'\n') {
+        for slice in input.trim_end().as_bytes().split(|&x| x == b'\n') {
             let (a, b) = slice.split_at(slice.len() / 2);
             let _ = lines.try_push((bitset(a), bitset(b)));
         }
@@ -22,24 +22,26 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = usize;
-    fn part_one(self) -> Self::Output1 {
+    fn part_one(self) -> aoc::Result<Self::Output1> {
         let mut errors = 0;
         for (a, b) in self.0 {
             errors += (a & b).trailing_zeros() as usize;
         }
-        errors
+        Ok(errors)
     }
 
     type Output2 = usize;
-    fn part_two(self) -> Self::Output2 {
+    fn part_two(self) -> aoc::Result<Self::Output2> {
         let mut badges = 0;
         for [(a1, a2), (b1, b2), (c1, c2)] in self.0.as_chunks().0 {
             badges += ((a1 | a2) & (b1 | b2) & (c1 | c2)).trailing_zeros() as usize;
         }
-        badges
+        Ok(badges)
     }
 }
 
+aoc::register!(Solution);
+
 fn bitset(x: &[u8]) -> usize {
     let mut set = 0;
     for &x in x {
@@ -56,30 +58,6 @@ fn bitset(x: &[u8]) -> usize {
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw";
-
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
 
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 157);
-    }
-
-    #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 70);
-    }
+    aoc::test_challenge!(Solution);
 }
@@ -1,22 +1,14 @@
 use aoc::{Challenge, Parser as ChallengeParser};
-use nom::{bytes::complete::tag, character::complete::line_ending, IResult, Parser};
-use parsers::{number, ParserExt};
+use nom::{bytes::complete::tag, IResult, Parser};
+use parsers::{AocParse, ParserExt};
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, AocParse)]
+#[separator("-")]
 pub struct Range {
     start: usize,
     end: usize,
 }
 
-impl Range {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        number
-            .separated_array(tag("-"))
-            .map(|[start, end]| Range { start, end })
-            .parse(input)
-    }
-}
-
 #[derive(Debug, PartialEq, Clone)]
 pub struct Solution(Vec<(Range, Range)>);
 
@@ -25,7 +17,7 @@ impl ChallengeParser for Solution {
         Range::parse
             .separated_array(tag(","))
             .map(|[a, b]| (a, b))
-            .separated_list1(line_ending)
+            .lines()
             .map(Self)
             .parse(input)
     }
@@ -35,55 +27,33 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = usize;
-    fn part_one(self) -> Self::Output1 {
+    fn part_one(self) -> aoc::Result<Self::Output1> {
         let mut count = 0;
         for (a, b) in self.0 {
             if a.start <= b.start && b.end <= a.end || b.start <= a.start && a.end <= b.end {
                 count += 1;
             }
         }
-        count
+        Ok(count)
     }
 
     type Output2 = usize;
-    fn part_two(self) -> Self::Output2 {
+    fn part_two(self) -> aoc::Result<Self::Output2> {
         let mut count = 0;
         for (a, b) in self.0 {
             if a.start <= b.end && b.start <= a.end {
                 count += 1;
             }
         }
-        count
+        Ok(count)
     }
 }
 
+aoc::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "2-4,6-8
-2-3,4-5
-5-7,7-9
-2-8,3-7
-6-6,4-6
-2-6,4-8";
 
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
-
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 2);
-    }
-
-    #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 4);
-    }
+    aoc::test_challenge!(Solution);
 }
@@ -5,7 +5,11 @@ use std::simd::{u8x16, Mask, Simd, SimdPartialEq, SimdPartialOrd};
 use aoc::{Challenge, Parser as ChallengeParser};
 use arrayvec::ArrayString;
 use nom::{
-    bytes::complete::tag, character::streaming::line_ending, sequence::tuple, IResult, Parser,
+    bytes::complete::tag,
+    character::streaming::line_ending,
+    error::{Error, ErrorKind},
+    sequence::tuple,
+    Err, IResult, Parser,
 };
 use parsers::{number, ParserExt};
 
@@ -45,8 +49,9 @@ pub struct Solution {
 
 impl ChallengeParser for Solution {
     fn parse(input: &'static str) -> IResult<&'static str, Self> {
-        let line_length = input.find('\n').unwrap() + 1;
-        let block_length = input.find("\n\n").unwrap() + 1;
+        let missing = || Err::Failure(Error::new(input, ErrorKind::TakeUntil));
+        let line_length = input.find('\n').ok_or_else(missing)? + 1;
+        let block_length = input.find("\n\n").ok_or_else(missing)? + 1;
         let stack_count = line_length / 4;
 
         let (data, input) = input.split_at(block_length - line_length);
@@ -213,49 +218,23 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = ArrayString<16>;
-    fn part_one(mut self) -> Self::Output1 {
+    fn part_one(mut self) -> aoc::Result<Self::Output1> {
         let (stacks, offsets) = self.solve_inner(true);
-        self.answer(stacks, offsets)
+        Ok(self.answer(stacks, offsets))
     }
 
     type Output2 = ArrayString<16>;
-    fn part_two(mut self) -> Self::Output2 {
+    fn part_two(mut self) -> aoc::Result<Self::Output2> {
         let (stacks, offsets) = self.solve_inner(false);
-        self.answer(stacks, offsets)
+        Ok(self.answer(stacks, offsets))
     }
 }
 
+aoc::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "    [D]    
-[N] [C]    
-[Z] [M] [P]
- 1   2   3 
-
-move 1 from 2 to 1
-move 3 from 1 to 3
-move 2 from 2 to 1
-move 1 from 1 to 2
-";
-
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
-
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one().as_str(), "CMZ");
-    }
 
-    #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two().as_str(), "MCD");
-    }
+    aoc::test_challenge!(Solution);
 }
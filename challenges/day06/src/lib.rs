@@ -1,7 +1,7 @@
 #![feature(portable_simd)]
 use std::simd::{u8x32, SimdUint};
 
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{AocError, Challenge, Parser as ChallengeParser};
 use nom::IResult;
 
 #[derive(Debug, PartialEq, Clone)]
@@ -15,11 +15,13 @@ impl ChallengeParser for Solution {
 }
 
 impl Solution {
+    /// The position after the first `n` different characters in a row,
+    /// or `None` if there aren't any
     #[inline(always)]
-    fn solve(self, n: usize) -> usize {
+    fn solve(self, n: usize) -> Option<usize> {
         let mut counter = u8x32::default();
-        for i in 0..n {
-            counter.as_mut_array()[self.0[i] as usize & 0x1f] += 1;
+        for &b in self.0.get(..n)? {
+            counter.as_mut_array()[b as usize & 0x1f] += 1;
         }
 
         let mut i = n;
@@ -35,11 +37,12 @@ impl Solution {
             // 14*14 is 196 which does not overflow u8.
             let sum = (counter * counter).reduce_sum();
             if sum == n as u8 {
-                return i;
+                return Some(i);
             }
 
+            let &next = self.0.get(i)?;
             counter.as_mut_array()[self.0[i - n] as usize & 0x1f] -= 1;
-            counter.as_mut_array()[self.0[i] as usize & 0x1f] += 1;
+            counter.as_mut_array()[next as usize & 0x1f] += 1;
 
             i += 1;
         }
@@ -50,82 +53,32 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = usize;
-    fn part_one(self) -> Self::Output1 {
-        self.solve(4)
+    fn part_one(self) -> aoc::Result<Self::Output1> {
+        self.solve(4).ok_or(AocError::Unsolvable)
     }
 
     type Output2 = usize;
-    fn part_two(self) -> Self::Output2 {
-        self.solve(14)
+    fn part_two(self) -> aoc::Result<Self::Output2> {
+        self.solve(14).ok_or(AocError::Unsolvable)
     }
 }
 
+aoc::register!(Solution);
+
 #[cfg(test)]
 mod tests {
+    use aoc::{AocError, Challenge};
+
     use super::Solution;
-    use aoc::{Challenge, Parser};
 
-    #[test]
-    fn part_one() {
-        assert_eq!(
-            Solution::parse("bvwbjplbgvbhsrlpgdmjqwftvncz")
-                .unwrap()
-                .1
-                .part_one(),
-            5
-        );
-        assert_eq!(
-            Solution::parse("nppdvjthqldpwncqszvftbrmjlhg")
-                .unwrap()
-                .1
-                .part_one(),
-            6
-        );
-        assert_eq!(
-            Solution::parse("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg")
-                .unwrap()
-                .1
-                .part_one(),
-            10
-        );
-        assert_eq!(
-            Solution::parse("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw")
-                .unwrap()
-                .1
-                .part_one(),
-            11
-        );
-    }
+    aoc::test_challenge!(Solution);
 
     #[test]
-    fn part_two() {
-        assert_eq!(
-            Solution::parse("bvwbjplbgvbhsrlpgdmjqwftvncz")
-                .unwrap()
-                .1
-                .part_two(),
-            23
-        );
-        assert_eq!(
-            Solution::parse("nppdvjthqldpwncqszvftbrmjlhg")
-                .unwrap()
-                .1
-                .part_two(),
-            23
-        );
-        assert_eq!(
-            Solution::parse("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg")
-                .unwrap()
-                .1
-                .part_two(),
-            29
-        );
-        assert_eq!(
-            Solution::parse("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw")
-                .unwrap()
-                .1
-                .part_two(),
-            26
-        );
+    fn no_marker() {
+        let solution = aoc::parse::<Solution>("abcabcabc\n").unwrap();
+        assert_eq!(solution.clone().part_one(), Err(AocError::Unsolvable));
+        assert_eq!(solution.part_two(), Err(AocError::Unsolvable));
+        let solution = aoc::parse::<Solution>("").unwrap();
+        assert_eq!(solution.part_one(), Err(AocError::Unsolvable));
     }
 }
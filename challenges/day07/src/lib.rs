@@ -2,7 +2,7 @@
 
 use std::simd::{u32x8, u8x8, SimdPartialOrd, SimdUint};
 
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{AocError, Challenge, Parser as ChallengeParser};
 use nom::IResult;
 
 #[derive(Debug, PartialEq, Clone)]
@@ -81,62 +81,33 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = u32;
-    fn part_one(self) -> Self::Output1 {
-        self.0.into_iter().filter(|x| *x <= 100000).sum()
+    fn part_one(self) -> aoc::Result<Self::Output1> {
+        Ok(self.0.into_iter().filter(|x| *x <= 100000).sum())
     }
 
     type Output2 = u32;
-    fn part_two(self) -> Self::Output2 {
-        let minimum = self.0.last().unwrap() - 40_000_000;
-        self.0.into_iter().filter(|&v| v > minimum).min().unwrap()
+    fn part_two(self) -> aoc::Result<Self::Output2> {
+        // the root directory is the last to be closed
+        let root = self
+            .0
+            .last()
+            .ok_or(AocError::InvalidInput("no directories found"))?;
+        let minimum = root
+            .checked_sub(40_000_000)
+            .ok_or(AocError::InvalidInput("disk already has enough free space"))?;
+        self.0
+            .into_iter()
+            .filter(|&v| v > minimum)
+            .min()
+            .ok_or(AocError::Unsolvable)
     }
 }
 
+aoc::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "$ cd /
-$ ls
-dir a
-14848514 b.txt
-8504156 c.dat
-dir d
-$ cd a
-$ ls
-dir e
-29116 f
-2557 g
-62596 h.lst
-$ cd e
-$ ls
-584 i
-$ cd ..
-$ cd ..
-$ cd d
-$ ls
-4060174 j
-8033020 d.log
-5626152 d.ext
-7214296 k
-";
-
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
 
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 95437);
-    }
-
-    #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 24933642);
-    }
+    aoc::test_challenge!(Solution);
 }
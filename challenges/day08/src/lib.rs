@@ -111,43 +111,21 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = u32;
-    fn part_one(self) -> Self::Output1 {
-        self.1
+    fn part_one(self) -> aoc::Result<Self::Output1> {
+        Ok(self.1)
     }
 
     type Output2 = u32;
-    fn part_two(self) -> Self::Output2 {
-        self.0
+    fn part_two(self) -> aoc::Result<Self::Output2> {
+        Ok(self.0)
     }
 }
 
+aoc::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "30373
-25512
-65332
-33549
-35390
-";
-
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
 
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 21);
-    }
-
-    #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 8);
-    }
+    aoc::test_challenge!(Solution);
 }
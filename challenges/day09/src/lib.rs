@@ -1,5 +1,9 @@
 use aoc::{Challenge, Parser as ChallengeParser};
-use nom::IResult;
+use nom::{
+    error::{Error, ErrorKind},
+    Err, IResult,
+};
+use parsers::bytes::number;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Solution(u32, u32);
@@ -10,8 +14,13 @@ impl ChallengeParser for Solution {
         let mut t9: Vec<u64> = Vec::with_capacity(8192);
         let mut knots = [(0, 0); 10];
 
+        // `at` is the offset of the line in the input, so that errors point into it
+        let fail = |at: usize, kind| Err(Err::Error(Error::new(&input[at..], kind)));
+        let mut at = 0;
         for line in input.as_bytes().split(|b| *b == b'\n') {
-            if line.len() < 3 {
+            let start = at;
+            at += line.len() + 1;
+            if line.is_empty() {
                 continue;
             }
             let (x, y) = match line[0] {
@@ -19,12 +28,16 @@ impl ChallengeParser for Solution {
                 b'D' => (0, -1),
                 b'L' => (-1, 0),
                 b'R' => (1, 0),
-                _ => continue,
+                _ => return fail(start, ErrorKind::OneOf),
             };
-            let mut distance = line[2] - b'0';
-            if line.len() == 4 {
-                distance = 10 * distance + (line[3] - b'0');
+            if line.get(1) != Some(&b' ') {
+                return fail(start + 1, ErrorKind::Char);
             }
+            let distance = match number::<u8>(&line[2..]) {
+                Ok((b"" | b"\r", distance)) => distance,
+                Ok((rest, _)) => return fail(start + line.len() - rest.len(), ErrorKind::CrLf),
+                Err(err) => return Err(err.map(|err| Error::new(&input[start + 2..], err.code))),
+            };
 
             for _ in 0..distance {
                 knots[0].0 += x;
@@ -56,16 +69,18 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = u32;
-    fn part_one(self) -> Self::Output1 {
-        self.0
+    fn part_one(self) -> aoc::Result<Self::Output1> {
+        Ok(self.0)
     }
 
     type Output2 = u32;
-    fn part_two(self) -> Self::Output2 {
-        self.1
+    fn part_two(self) -> aoc::Result<Self::Output2> {
+        Ok(self.1)
     }
 }
 
+aoc::register!(Solution);
+
 fn drag_knot(head: (i16, i16), tail: (i16, i16)) -> (i16, i16) {
     let dx = head.0 - tail.0;
     let dy = head.1 - tail.1;
@@ -115,43 +130,22 @@ fn set_bit(v: &mut Vec<u64>, i: usize) {
 
 #[cfg(test)]
 mod tests {
-    use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "R 4
-U 4
-L 3
-D 1
-R 4
-D 1
-L 5
-R 2
-";
+    use aoc::AocError;
 
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
+    use super::Solution;
 
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 13);
-    }
+    aoc::test_challenge!(Solution);
 
-    const INPUT2: &str = "R 5
-U 8
-L 8
-D 3
-R 17
-D 10
-L 25
-U 20
-";
     #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT2).unwrap().1;
-        assert_eq!(output.part_two(), 36);
+    fn bad_lines() {
+        let offset = |input| match aoc::parse::<Solution>(input) {
+            Err(AocError::Parse { offset, .. }) => Some(offset),
+            _ => None,
+        };
+        assert_eq!(offset("R 4\nU x\n"), Some(6));
+        assert_eq!(offset("R 4\nU 300\n"), Some(6));
+        assert_eq!(offset("R 4\nU 4x\n"), Some(7));
+        assert_eq!(offset("R 4\nX 4\n"), Some(4));
+        assert_eq!(offset("R 4\r\nU 4"), None);
     }
 }
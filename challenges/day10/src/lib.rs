@@ -1,22 +1,37 @@
 use aoc::{Challenge, Parser as ChallengeParser};
 use arrayvec::ArrayString;
-use nom::IResult;
+use nom::{
+    branch::alt, bytes::complete::tag, character::complete::line_ending, combinator::eof, IResult,
+    Parser,
+};
+use parsers::{checked_signed_number, ParserExt};
 use phf::{phf_map, Map};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Solution(i32, ArrayString<8>);
 
+/// A `noop` or `addx` line, giving the number added for `addx`
+fn instruction(input: &str) -> IResult<&str, Option<i32>> {
+    alt((
+        tag("noop").map(|_| None),
+        checked_signed_number.preceded_by(tag("addx ")).map(Some),
+    ))
+    .followed_by(alt((line_ending, eof)))
+    .parse(input)
+}
+
 impl ChallengeParser for Solution {
-    fn parse(input: &'static str) -> IResult<&'static str, Self> {
+    fn parse(mut input: &'static str) -> IResult<&'static str, Self> {
         let mut x = 1;
         let mut cycle: usize = 1;
         let mut output = [0u32; 8];
         let mut total = 0;
-        for line in input.lines() {
-            let (cycles, newx) = if let Some(n) = line.strip_prefix("addx ") {
-                (2, x + n.parse::<i32>().unwrap())
-            } else {
-                (1, x)
+        while !input.is_empty() {
+            let (rest, addx) = instruction(input)?;
+            input = rest;
+            let (cycles, newx) = match addx {
+                Some(n) => (2, x + n),
+                None => (1, x),
             };
 
             for _ in 0..cycles {
@@ -74,10 +89,11 @@ impl ChallengeParser for Solution {
                 0b000000000111111000000111_u32 => 'Y',
                 0b100011100101101001110001_u32 => 'Z',
             };
-            s.push(INT_MAP[&char]);
+            // the examples don't draw letters
+            s.push(INT_MAP.get(&char).copied().unwrap_or('?'));
         }
 
-        Ok(("", Self(total, s)))
+        Ok((input, Self(total, s)))
     }
 }
 
@@ -85,27 +101,35 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = i32;
-    fn part_one(self) -> Self::Output1 {
-        self.0
+    fn part_one(self) -> aoc::Result<Self::Output1> {
+        Ok(self.0)
     }
 
     type Output2 = ArrayString<8>;
-    fn part_two(self) -> Self::Output2 {
-        self.1
+    fn part_two(self) -> aoc::Result<Self::Output2> {
+        Ok(self.1)
     }
 }
 
+aoc::register!(Solution);
+
 #[cfg(test)]
 mod tests {
+    use aoc::AocError;
+
     use super::Solution;
-    use aoc::Parser;
 
     const INPUT: &str = include_str!("../input.txt");
 
+    aoc::test_challenge! {
+        Solution;
+        (INPUT, 17020, "RLEZFLGE"),
+    }
+
     #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.0, 17020);
-        assert_eq!(output.1.as_str(), "RLEZFLGE");
+    fn trailing_text() {
+        let err = aoc::parse::<Solution>("noop\naddx 3x\nnoop\n").unwrap_err();
+        assert!(matches!(err, AocError::Parse { offset: 11, .. }));
+        assert!(aoc::parse::<Solution>("noop\naddx -5").is_ok());
     }
 }
@@ -2,28 +2,20 @@
 
 use aoc::{Challenge, Parser as ChallengeParser};
 use arrayvec::ArrayVec;
-use nom::{
-    branch::alt,
-    bytes::complete::{tag, take_until},
-    IResult, Parser,
-};
-use parsers::{number, ParserExt};
-
-#[derive(Debug, PartialEq, Clone, Copy)]
+use nom::{bytes::complete::tag, character::complete::line_ending, IResult, Parser};
+use parsers::{number, AocParse, ParserExt};
+
+#[derive(Debug, PartialEq, Clone, Copy, AocParse)]
+#[prefix("new = old ")]
 enum Operation {
+    #[tag("* old")]
     Square,
+    #[tag("* ")]
     Mul(u64),
+    #[tag("+ ")]
     Add(u64),
 }
 impl Operation {
-    fn parse(input: &'static str) -> IResult<&'static str, Self> {
-        alt((
-            tag("new = old * old").map(|_| Self::Square),
-            number.preceded_by(tag("new = old * ")).map(Self::Mul),
-            number.preceded_by(tag("new = old + ")).map(Self::Add),
-        ))
-        .parse(input)
-    }
     fn apply(self, x: u64) -> u64 {
         match self {
             Operation::Square => x * x,
@@ -33,45 +25,24 @@ impl Operation {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, AocParse)]
 struct Monkey {
+    #[prefix("Monkey ")]
+    id: usize,
+    #[prefix(":\n  Starting items: ")]
+    #[with(number.separated_list1(tag(", ")))]
     items: ArrayVec<u64, 8>,
+    #[prefix("\n  Operation: ")]
+    #[with(Operation::parse)]
     op: Operation,
+    #[prefix("\n  Test: divisible by ")]
+    #[with(number.map(Div::new))]
     test: Div,
+    #[prefix("\n    If true: throw to monkey ")]
+    #[with(number.separated_array(tag("\n    If false: throw to monkey ")))]
     throws: [usize; 2],
 }
 
-impl Monkey {
-    fn parse(input: &'static str) -> IResult<&'static str, Self> {
-        let (input, _) = tag("Monkey ").parse(input)?;
-        let (input, _) = take_until("items: ").parse(input)?;
-        let (input, _) = tag("items: ").parse(input)?;
-        let (input, items) = number.separated_list1(tag(", ")).parse(input)?;
-        let (input, op) = Operation::parse
-            .preceded_by(tag("\n  Operation: "))
-            .parse(input)?;
-        let (input, test) = number
-            .preceded_by(tag("\n  Test: divisible by "))
-            .parse(input)?;
-        let (input, throw1) = number
-            .preceded_by(tag("\n    If true: throw to monkey "))
-            .parse(input)?;
-        let (input, throw2) = number
-            .preceded_by(tag("\n    If false: throw to monkey "))
-            .parse(input)?;
-
-        Ok((
-            input,
-            Self {
-                items,
-                op,
-                test: Div::new(test),
-                throws: [throw1, throw2],
-            },
-        ))
-    }
-}
-
 #[derive(Debug, Clone)]
 pub struct Solution(ArrayVec<Monkey, 8>);
 
@@ -79,6 +50,7 @@ impl ChallengeParser for Solution {
     fn parse(input: &'static str) -> IResult<&'static str, Self> {
         Monkey::parse
             .separated_list1(tag("\n\n"))
+            .followed_by(line_ending)
             .map(Self)
             .parse(input)
     }
@@ -91,6 +63,8 @@ impl Solution {
         let lcm = Div::new(self.0.iter().map(|m| m.test.1).product());
 
         for (i, m) in self.0.iter().enumerate() {
+            // the throws are to the monkey's position, so they must be in order
+            assert_eq!(m.id, i);
             assert!(m.throws[0] < self.0.len());
             assert!(m.throws[1] < self.0.len());
             assert_ne!(m.throws[0], i);
@@ -276,65 +250,21 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = usize;
-    fn part_one(self) -> Self::Output1 {
-        self.solve(3, 20)
+    fn part_one(self) -> aoc::Result<Self::Output1> {
+        Ok(self.solve(3, 20))
     }
 
     type Output2 = usize;
-    fn part_two(self) -> Self::Output2 {
-        self.solve(1, 10000)
+    fn part_two(self) -> aoc::Result<Self::Output2> {
+        Ok(self.solve(1, 10000))
     }
 }
 
+aoc::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "Monkey 0:
-  Starting items: 79, 98
-  Operation: new = old * 19
-  Test: divisible by 23
-    If true: throw to monkey 2
-    If false: throw to monkey 3
-
-Monkey 1:
-  Starting items: 54, 65, 75, 74
-  Operation: new = old + 6
-  Test: divisible by 19
-    If true: throw to monkey 2
-    If false: throw to monkey 0
-
-Monkey 2:
-  Starting items: 79, 60, 97
-  Operation: new = old * old
-  Test: divisible by 13
-    If true: throw to monkey 1
-    If false: throw to monkey 3
-
-Monkey 3:
-  Starting items: 74
-  Operation: new = old + 3
-  Test: divisible by 17
-    If true: throw to monkey 0
-    If false: throw to monkey 1
-";
-
-    #[test]
-    fn parse() {
-        let (input, output) = Solution::parse(INPUT).unwrap();
-        println!("{input:?} {output:?}");
-    }
 
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 10605);
-    }
-
-    #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 2713310158);
-    }
+    aoc::test_challenge!(Solution);
 }
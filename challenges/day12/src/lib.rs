@@ -1,58 +1,50 @@
-use aoc::{Challenge, Parser as ChallengeParser};
-use nom::IResult;
+use aoc::{AocError, Challenge, Parser as ChallengeParser};
+use nom::{
+    error::{Error, ErrorKind},
+    Err, IResult,
+};
+use parsers::{byte_grid, Grid};
 use pathfinding::directed::bfs;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Solution {
-    map: &'static [u8],
-    stride: usize,
+    map: Grid<u8>,
     end: usize,
 }
 
 impl ChallengeParser for Solution {
     fn parse(input: &'static str) -> IResult<&'static str, Self> {
-        // memchr?
-        let width = input.as_bytes().iter().position(|b| *b == b'\n').unwrap();
-        let end = input.as_bytes().iter().position(|b| *b == b'E').unwrap();
-        Ok((
-            "",
-            Self {
-                map: input.as_bytes(),
-                stride: width + 1,
-                end,
-            },
-        ))
+        let (rest, map) = byte_grid(input)?;
+        let end = map.cells().iter().position(|b| *b == b'E');
+        let end = end.ok_or_else(|| Err::Failure(Error::new(input, ErrorKind::TakeUntil)))?;
+        Ok((rest, Self { map, end }))
     }
 }
 
 impl Solution {
-    fn solve(self, any: bool) -> usize {
-        let Self { map, stride, end } = self;
+    fn solve(self, any: bool) -> Option<usize> {
+        let Self { map, end } = self;
+        let cells = map.cells();
         // pathfind from E to S (or any 'a' if the flag is set)
         bfs::bfs(
             &end,
             |&p| {
-                let mut vp = map[p];
+                let mut vp = cells[p];
                 if vp == b'E' {
                     vp = b'z'
                 }
                 // try step in any right, down, left, up direction
-                [p + 1, p + stride, p.wrapping_sub(1), p.wrapping_sub(stride)]
-                    .into_iter()
-                    .filter(|&q| q < map.len())
-                    .filter(move |&q| {
-                        let mut vq = map[q];
-                        if vq == b'S' {
-                            vq = b'a'
-                        }
-                        vp != b'\n' && vp <= vq + 1
-                    })
+                map.neighbours4(p).filter(move |&q| {
+                    let mut vq = cells[q];
+                    if vq == b'S' {
+                        vq = b'a'
+                    }
+                    vp <= vq + 1
+                })
             },
-            |p| map[*p] == b'S' || (any && map[*p] == b'a'),
+            |p| cells[*p] == b'S' || (any && cells[*p] == b'a'),
         )
-        .unwrap()
-        .len()
-            - 1
+        .map(|path| path.len() - 1)
     }
 }
 
@@ -60,43 +52,21 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = usize;
-    fn part_one(self) -> Self::Output1 {
-        self.solve(false)
+    fn part_one(self) -> aoc::Result<Self::Output1> {
+        self.solve(false).ok_or(AocError::Unsolvable)
     }
 
     type Output2 = usize;
-    fn part_two(self) -> Self::Output2 {
-        self.solve(true)
+    fn part_two(self) -> aoc::Result<Self::Output2> {
+        self.solve(true).ok_or(AocError::Unsolvable)
     }
 }
 
+aoc::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "Sabqponm
-abcryxxl
-accszExk
-acctuvwj
-abdefghi
-";
-
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
-
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 31);
-    }
 
-    #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 29);
-    }
+    aoc::test_challenge!(Solution);
 }
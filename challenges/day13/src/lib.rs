@@ -2,7 +2,11 @@ use std::{cmp, fmt, ops::Range};
 
 use aoc::{Challenge, Parser as ChallengeParser};
 use bytemuck::TransparentWrapper;
-use nom::IResult;
+use nom::{
+    error::{Error, ErrorKind},
+    Err, IResult,
+};
+use parsers::bytes::number;
 
 #[derive(Clone, Copy, Debug)]
 enum Entry {
@@ -12,49 +16,54 @@ enum Entry {
     Value(u8),
 }
 
+type Bytes = &'static [u8];
+
+/// Takes the next byte, failing at the end of the input
+fn next(input: Bytes) -> IResult<Bytes, u8> {
+    match input.split_first() {
+        Some((&first, rest)) => Ok((rest, first)),
+        None => Err(Err::Failure(Error::new(input, ErrorKind::Eof))),
+    }
+}
+
+fn expect(b: u8) -> impl Fn(Bytes) -> IResult<Bytes, ()> {
+    move |input| match next(input)? {
+        (rest, first) if first == b => Ok((rest, ())),
+        _ => Err(Err::Failure(Error::new(input, ErrorKind::Char))),
+    }
+}
+
 impl Entry {
-    fn parse(arena: &mut Vec<Entry>, input: &'static [u8]) -> &'static [u8] {
-        let (mut first, mut input) = input.split_first().unwrap();
-        if *first == b'[' {
+    fn parse(arena: &mut Vec<Entry>, input: Bytes) -> IResult<Bytes, ()> {
+        if let (input, b'[') = next(input)? {
             let prefix_index = arena.len();
-            let range;
             arena.push(Entry::List(0));
-            (input, range) = Entry::parse_list(arena, input);
+            let (input, range) = Entry::parse_list(arena, input)?;
             arena[prefix_index] = Entry::List(range.len() as u8);
+            Ok((input, ()))
         } else {
-            let mut n = *first - b'0';
-            while let Some(b'0'..=b'9') = input.first() {
-                (first, input) = input.split_first().unwrap();
-                n *= 10;
-                n += *first - b'0';
-            }
+            let (input, n) = number(input)?;
             arena.push(Entry::Value(n));
+            Ok((input, ()))
         }
-        input
     }
 
-    fn parse_list(
-        arena: &mut Vec<Entry>,
-        mut input: &'static [u8],
-    ) -> (&'static [u8], Range<usize>) {
+    fn parse_list(arena: &mut Vec<Entry>, mut input: Bytes) -> IResult<Bytes, Range<usize>> {
         let start = arena.len();
         // skip empty lists
-        if *input.first().unwrap() != b']' {
-            loop {
-                let i = Entry::parse(arena, input);
-
-                // check for `,` or `]`
-                let next;
-                (next, input) = i.split_first().unwrap();
-                if *next == b']' {
-                    break;
-                }
-            }
-        } else {
-            (_, input) = input.split_first().unwrap();
+        if let (rest, b']') = next(input)? {
+            return Ok((rest, start..arena.len()));
         }
+        loop {
+            (input, _) = Entry::parse(arena, input)?;
 
-        (input, start..arena.len())
+            // check for `,` or `]`
+            match next(input)? {
+                (rest, b',') => input = rest,
+                (rest, b']') => break Ok((rest, start..arena.len())),
+                _ => break Err(Err::Failure(Error::new(input, ErrorKind::Char))),
+            }
+        }
     }
 }
 
@@ -187,14 +196,23 @@ pub struct Solution(usize, usize);
 
 impl ChallengeParser for Solution {
     fn parse(input: &'static str) -> IResult<&'static str, Self> {
+        // the parser only steps over ascii, so every offset is on a char boundary
+        let offset = |rest: &[u8]| input.len() - rest.len();
+        match Self::parse_bytes(input.as_bytes()) {
+            Ok((rest, solution)) => Ok((&input[offset(rest)..], solution)),
+            Err(err) => Err(err.map(|err| Error::new(&input[offset(err.input)..], err.code))),
+        }
+    }
+}
+
+impl Solution {
+    fn parse_bytes(mut input: Bytes) -> IResult<Bytes, Self> {
         let mut sum = 0;
         let mut x = 1;
         let mut y = 2;
 
         let mut arena = Vec::with_capacity(512);
 
-        let mut input = input.as_bytes();
-
         // let two = EntrySlice::wrap_ref(&[Entry::Value(2)]);
         // let six = EntrySlice::wrap_ref(&[Entry::Value(6)]);
 
@@ -203,17 +221,20 @@ impl ChallengeParser for Solution {
                 break;
             }
             if i > 1 {
-                input = &input[1..]; // trim newline
+                (input, _) = expect(b'\n')(input)?; // trim newline
             }
-            input = &input[1..]; // trim `[`
+            (input, _) = expect(b'[')(input)?;
 
             let left;
             let right;
 
-            (input, left) = Entry::parse_list(&mut arena, input);
-            input = &input[2..]; // trim `\n[`
-            (input, right) = Entry::parse_list(&mut arena, input);
-            input = &input[1..]; // trim `\n`
+            (input, left) = Entry::parse_list(&mut arena, input)?;
+            (input, _) = expect(b'\n')(input)?;
+            (input, _) = expect(b'[')(input)?;
+            (input, right) = Entry::parse_list(&mut arena, input)?;
+            if !input.is_empty() {
+                (input, _) = expect(b'\n')(input)?;
+            }
 
             // construct our entryslice helpers
             let left = EntrySlice::wrap_ref(&arena[left]);
@@ -232,7 +253,7 @@ impl ChallengeParser for Solution {
             arena.clear();
         }
 
-        Ok(("", Self(sum, x * y)))
+        Ok((input, Self(sum, x * y)))
     }
 }
 
@@ -240,61 +261,28 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = usize;
-    fn part_one(self) -> Self::Output1 {
-        self.0
+    fn part_one(self) -> aoc::Result<Self::Output1> {
+        Ok(self.0)
     }
 
     type Output2 = usize;
-    fn part_two(self) -> Self::Output2 {
-        self.1
+    fn part_two(self) -> aoc::Result<Self::Output2> {
+        Ok(self.1)
     }
 }
 
+aoc::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "[1,1,3,1,1]
-[1,1,5,1,1]
-
-[[1],[2,3,4]]
-[[1],4]
-
-[9]
-[[8,7,6]]
-
-[[4,4],4,4]
-[[4,4],4,4,4]
-
-[7,7,7,7]
-[7,7,7]
 
-[]
-[3]
-
-[[[]]]
-[[]]
-
-[1,[2,[3,[4,[5,6,7]]]],8,9]
-[1,[2,[3,[4,[5,6,0]]]],8,9]
-";
-
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
-
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 13);
-    }
+    aoc::test_challenge!(Solution);
 
     #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 140);
+    fn unfinished_list() {
+        use aoc::Parser;
+        assert!(Solution::parse("[1,[2]\n[3]\n").is_err());
+        assert!(Solution::parse("[1,\n").is_err());
     }
 }
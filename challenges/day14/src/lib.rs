@@ -4,21 +4,16 @@ use core::fmt;
 use std::fmt::Write;
 
 use aoc::{Challenge, Parser as ChallengeParser};
-use nom::{bytes::complete::tag, IResult, Parser};
-use parsers::{number, ParserExt};
-
-#[derive(Debug, PartialEq, Clone, Copy)]
+use nom::{
+    error::{Error, ErrorKind},
+    Err, IResult,
+};
+use parsers::AocParse;
+
+#[derive(Debug, PartialEq, Clone, Copy, AocParse)]
+#[separator(",")]
 pub struct Point(u16, u16);
 
-impl Point {
-    fn parse(input: &'static str) -> IResult<&'static str, Self> {
-        number
-            .separated_array(tag(","))
-            .map(|[x, y]| Self(x, y))
-            .parse(input)
-    }
-}
-
 #[derive(Debug, PartialEq, Clone)]
 pub struct Solution(Grid);
 
@@ -36,7 +31,9 @@ impl ChallengeParser for Solution {
                 a = b;
             }
 
-            input = input.strip_prefix('\n').unwrap();
+            input = input
+                .strip_prefix('\n')
+                .ok_or_else(|| Err::Error(Error::new(input, ErrorKind::CrLf)))?;
             if input.is_empty() {
                 break;
             }
@@ -50,16 +47,18 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = usize;
-    fn part_one(mut self) -> Self::Output1 {
-        self.0.fill_one()
+    fn part_one(mut self) -> aoc::Result<Self::Output1> {
+        Ok(self.0.fill_one())
     }
 
     type Output2 = usize;
-    fn part_two(mut self) -> Self::Output2 {
-        self.0.fill_two()
+    fn part_two(mut self) -> aoc::Result<Self::Output2> {
+        Ok(self.0.fill_two())
     }
 }
 
+aoc::register!(Solution);
+
 #[derive(Debug, PartialEq, Clone)]
 struct Grid {
     grid: Vec<GridSpot>,
@@ -314,28 +313,6 @@ impl fmt::Display for GridSpot {
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
 
-    const INPUT: &str = "498,4 -> 498,6 -> 496,6
-503,4 -> 502,4 -> 502,9 -> 494,9
-";
-
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        // println!("{output:?}");
-        println!("{}", output.0);
-    }
-
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 24);
-    }
-
-    #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 93);
-    }
+    aoc::test_challenge!(Solution);
 }
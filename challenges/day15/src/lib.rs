@@ -2,9 +2,9 @@
 
 use std::ops::Range;
 
-use aoc::{Challenge, Parser as ChallengeParser};
-use nom::IResult;
-use parsers::split_many;
+use aoc::{AocError, Challenge, Parser as ChallengeParser};
+use nom::{IResult, Parser};
+use parsers::{template, ParserExt};
 
 #[derive(Debug, PartialEq, Clone)]
 struct Positions {
@@ -18,21 +18,11 @@ pub struct Solution<const N: i32>(Vec<Positions>);
 
 impl<const N: i32> ChallengeParser for Solution<N> {
     fn parse(input: &'static str) -> IResult<&'static str, Self> {
-        let mut positions = Vec::with_capacity(input.len() / 32);
-        for line in input.lines() {
-            if line.is_empty() {
-                continue;
-            }
-            let segments = ["Sensor at x=", ", y=", ": closest beacon is at x=", ", y="];
-            let [x1, y1, x2, y2] = split_many(line, segments).unwrap();
-            positions.push(Positions {
-                x1: x1.parse().unwrap(),
-                y1: y1.parse().unwrap(),
-                x2: x2.parse().unwrap(),
-                y2: y2.parse().unwrap(),
-            });
-        }
-        Ok(("", Self(positions)))
+        template!("Sensor at x={i32}, y={i32}: closest beacon is at x={i32}, y={i32}")
+            .map(|(x1, y1, x2, y2)| Positions { x1, y1, x2, y2 })
+            .lines()
+            .map(Self)
+            .parse(input)
     }
 }
 
@@ -40,7 +30,7 @@ impl<const N: i32> Challenge for Solution<N> {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = usize;
-    fn part_one(self) -> Self::Output1 {
+    fn part_one(self) -> aoc::Result<Self::Output1> {
         let mut ranges: Vec<Range<i32>> = Vec::with_capacity(self.0.len());
         let mut beacons = self
             .0
@@ -51,27 +41,30 @@ impl<const N: i32> Challenge for Solution<N> {
         beacons.sort();
         beacons.dedup();
 
-        self.build_range(N / 2, &mut ranges, i32::MIN..i32::MAX)
+        let covered = self
+            .build_range(N / 2, &mut ranges, i32::MIN..i32::MAX)
             .iter()
             .map(|r| r.len())
-            .sum::<usize>()
-            - beacons.len()
+            .sum::<usize>();
+        Ok(covered - beacons.len())
     }
 
     type Output2 = usize;
-    fn part_two(self) -> Self::Output2 {
+    fn part_two(self) -> aoc::Result<Self::Output2> {
         let mut ranges: Vec<Range<i32>> = Vec::with_capacity(self.0.len());
         for row in 0..=N {
             for [a, b] in self.build_range(row, &mut ranges, 0..N + 1).array_windows() {
                 if a.end < b.start {
-                    return (row as usize) + (a.end as usize) * 4000000;
+                    return Ok((row as usize) + (a.end as usize) * 4000000);
                 }
             }
         }
-        0
+        Err(AocError::Unsolvable)
     }
 }
 
+aoc::register!(Solution<4000000>);
+
 impl<const N: i32> Solution<N> {
     fn build_range<'a>(
         &self,
@@ -93,7 +86,7 @@ impl<const N: i32> Solution<N> {
             loop {
                 let Some((i, r)) = iter.next() else {
                     ranges.push(range);
-                    break
+                    break;
                 };
 
                 if range.end < r.start {
@@ -120,39 +113,6 @@ impl<const N: i32> Solution<N> {
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
-Sensor at x=9, y=16: closest beacon is at x=10, y=16
-Sensor at x=13, y=2: closest beacon is at x=15, y=3
-Sensor at x=12, y=14: closest beacon is at x=10, y=16
-Sensor at x=10, y=20: closest beacon is at x=10, y=16
-Sensor at x=14, y=17: closest beacon is at x=10, y=16
-Sensor at x=8, y=7: closest beacon is at x=2, y=10
-Sensor at x=2, y=0: closest beacon is at x=2, y=10
-Sensor at x=0, y=11: closest beacon is at x=2, y=10
-Sensor at x=20, y=14: closest beacon is at x=25, y=17
-Sensor at x=17, y=20: closest beacon is at x=21, y=22
-Sensor at x=16, y=7: closest beacon is at x=15, y=3
-Sensor at x=14, y=3: closest beacon is at x=15, y=3
-Sensor at x=20, y=1: closest beacon is at x=15, y=3
-";
 
-    #[test]
-    fn parse() {
-        let output = Solution::<20>::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
-
-    #[test]
-    fn part_one() {
-        let output = Solution::<20>::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 26);
-    }
-
-    #[test]
-    fn part_two() {
-        let output = Solution::<20>::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 56000011);
-    }
+    aoc::test_challenge!(Solution<20>);
 }
@@ -1,7 +1,7 @@
-use day15::Solution;
+use day15::Registered;
 
 fn main() {
     let input = include_str!("../input.txt");
-    aoc::check::<Solution<4000000>>(input);
-    // aoc::run::<Solution>(input);
+    aoc::check::<Registered>(input);
+    // aoc::run::<Registered>(input);
 }
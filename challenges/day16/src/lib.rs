@@ -1,7 +1,10 @@
 #![feature(get_many_mut)]
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{AocError, Challenge, Parser as ChallengeParser};
 use arrayvec::ArrayVec;
-use nom::IResult;
+use nom::{
+    branch::alt, bytes::complete::tag, character::complete::alpha1, sequence::pair, IResult, Parser,
+};
+use parsers::{template, ParserExt};
 use pathfinding::directed::astar;
 
 #[derive(Debug, PartialEq, Clone)]
@@ -28,29 +31,20 @@ pub struct Solution {
 
 impl ChallengeParser for Solution {
     fn parse(input: &'static str) -> IResult<&'static str, Self> {
-        let mut init = ArrayVec::<ValveInit, 64>::new();
-
-        for line in input.lines() {
-            let name = &line[6..8];
-
-            let Some((_, line)) = line.split_once('=') else { continue; };
-            let Some((flow_rate, line)) = line.split_once("; ") else { continue; };
-            let flow_rate = flow_rate.parse().unwrap();
-
-            let leads_to = if let Some(singular) = line.strip_prefix("tunnel leads to valve ") {
-                vec![singular]
-            } else if let Some(many) = line.strip_prefix("tunnels lead to valves ") {
-                many.split(", ").collect()
-            } else {
-                vec![]
-            };
-
-            init.push(ValveInit {
-                name,
-                flow_rate,
-                leads_to,
-            });
-        }
+        let tunnels = alt((
+            tag("tunnel leads to valve "),
+            tag("tunnels lead to valves "),
+        ));
+        let valve = pair(
+            template!("Valve {&str} has flow rate={usize}; "),
+            alpha1.separated_list1(tag(", ")).preceded_by(tunnels),
+        )
+        .map(|((name, flow_rate), leads_to)| ValveInit {
+            name,
+            flow_rate,
+            leads_to,
+        });
+        let (input, mut init): (_, ArrayVec<ValveInit, 64>) = valve.lines().parse(input)?;
 
         // we want highest flow at the beginning - this helps our heuristics
         init.sort_unstable_by_key(|x| std::cmp::Reverse(x.flow_rate));
@@ -102,12 +96,12 @@ impl ChallengeParser for Solution {
             valves[i].leads_to.retain(|x| init[x.0].flow_rate > 0)
         }
 
-        Ok(("", Self { start, valves }))
+        Ok((input, Self { start, valves }))
     }
 }
 
 impl Solution {
-    fn solve(&self, steps: usize, until: usize) -> usize {
+    fn solve(&self, steps: usize, until: usize) -> Option<usize> {
         #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
         struct Position {
             valve: usize,
@@ -199,8 +193,7 @@ impl Solution {
                 flow_remaining
             },
             |p| p.time + 1 == until,
-        )
-        .unwrap();
+        )?;
 
         // this should be roughly `(max * steps) - res.1` but that's giving me different answers for some reason...
         let mut total = 0;
@@ -212,7 +205,7 @@ impl Solution {
                 total += flow * (until - i.time);
             }
         }
-        total
+        Some(total)
     }
 }
 
@@ -220,48 +213,32 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = usize;
-    fn part_one(self) -> Self::Output1 {
-        self.solve(30, 30)
+    fn part_one(self) -> aoc::Result<Self::Output1> {
+        self.solve(30, 30).ok_or(AocError::Unsolvable)
     }
 
     type Output2 = usize;
-    fn part_two(self) -> Self::Output2 {
-        self.solve(26, 52)
+    fn part_two(self) -> aoc::Result<Self::Output2> {
+        self.solve(26, 52).ok_or(AocError::Unsolvable)
     }
 }
 
+aoc::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
-Valve BB has flow rate=13; tunnels lead to valves CC, AA
-Valve CC has flow rate=2; tunnels lead to valves DD, BB
-Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
-Valve EE has flow rate=3; tunnels lead to valves FF, DD
-Valve FF has flow rate=0; tunnels lead to valves EE, GG
-Valve GG has flow rate=0; tunnels lead to valves FF, HH
-Valve HH has flow rate=22; tunnel leads to valve GG
-Valve II has flow rate=0; tunnels lead to valves AA, JJ
-Valve JJ has flow rate=21; tunnel leads to valve II
-";
-
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:#?}");
-    }
 
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 1651);
-    }
+    aoc::test_challenge!(Solution);
 
     #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 1707);
+    fn bad_flow_rate() {
+        let input = "Valve AA has flow rate=x; tunnel leads to valve BB\n";
+        let err = aoc::parse::<Solution>(input).unwrap_err();
+        let report = err.report(input);
+        assert!(
+            report.starts_with("expected number at line 1, column 24"),
+            "{report}"
+        );
     }
 }
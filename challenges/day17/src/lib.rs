@@ -1,5 +1,8 @@
 use aoc::{Challenge, Parser as ChallengeParser};
-use nom::IResult;
+use nom::{
+    bytes::complete::is_a, character::complete::line_ending, combinator::opt, IResult, Parser,
+};
+use parsers::ParserExt;
 use pathfinding::prelude::brent;
 
 #[derive(Debug, PartialEq, Clone)]
@@ -7,7 +10,10 @@ pub struct Solution(&'static [u8]);
 
 impl ChallengeParser for Solution {
     fn parse(input: &'static str) -> IResult<&'static str, Self> {
-        Ok(("", Self(input.trim_end().as_bytes())))
+        is_a("<>")
+            .followed_by(opt(line_ending))
+            .map(|jets: &'static str| Self(jets.as_bytes()))
+            .parse(input)
     }
 }
 
@@ -28,7 +34,7 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = usize;
-    fn part_one(self) -> Self::Output1 {
+    fn part_one(self) -> aoc::Result<Self::Output1> {
         let mut bitset = Vec::<u8>::with_capacity(2022 * 7 / 2 / 32); // that is not a date
         bitset.resize(3 + 4, 0);
 
@@ -42,11 +48,11 @@ impl Challenge for Solution {
             (i, stack_height) = self.drop_block(piece % 5, i, stack_height, &mut bitset)
         }
 
-        stack_height
+        Ok(stack_height)
     }
 
     type Output2 = usize;
-    fn part_two(self) -> Self::Output2 {
+    fn part_two(self) -> aoc::Result<Self::Output2> {
         let mut bitset = Vec::<u8>::with_capacity(self.0.len() * 360);
         bitset.resize(3 + 4, 0);
 
@@ -81,10 +87,12 @@ impl Challenge for Solution {
                 ((p + 1) % 5, i, sh, bs)
             });
 
-        initial.2 + extra_stack_height
+        Ok(initial.2 + extra_stack_height)
     }
 }
 
+aoc::register!(Solution);
+
 impl Solution {
     fn drop_block(
         &self,
@@ -147,25 +155,13 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
 
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
-
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 3068);
-    }
+    aoc::test_challenge!(Solution);
 
     #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 1514285714288);
+    fn bad_jets() {
+        assert!(aoc::parse::<Solution>("").is_err());
+        assert!(aoc::parse::<Solution>("\n").is_err());
+        assert!(aoc::parse::<Solution>("<<>\n").is_ok());
     }
 }
@@ -1,20 +1,14 @@
 use aoc::{Challenge, Parser as ChallengeParser};
 use fxhash::FxHashSet;
-use nom::IResult;
+use nom::{IResult, Parser};
+use parsers::{template, ParserExt};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Solution(Vec<(u8, u8, u8)>);
 
 impl ChallengeParser for Solution {
     fn parse(input: &'static str) -> IResult<&'static str, Self> {
-        let mut points = Vec::with_capacity(input.len() / 6); // "a,b,c\n".len() == 6
-        for line in input.lines() {
-            let Some((x, yz)) = line.split_once(',') else { continue };
-            let Some((y, z)) = yz.split_once(',') else { continue };
-            points.push((x.parse().unwrap(), y.parse().unwrap(), z.parse().unwrap()));
-        }
-
-        Ok(("", Self(points)))
+        template!("{u8},{u8},{u8}").lines().map(Self).parse(input)
     }
 }
 
@@ -22,7 +16,7 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = usize;
-    fn part_one(mut self) -> Self::Output1 {
+    fn part_one(mut self) -> aoc::Result<Self::Output1> {
         let mut area = 6;
         self.0.sort_unstable_by_key(|&(a, b, c)| (a, b, c));
         self.0.iter().copied().reduce(|x, y| {
@@ -50,11 +44,11 @@ impl Challenge for Solution {
             }
             y
         });
-        area
+        Ok(area)
     }
 
     type Output2 = usize;
-    fn part_two(mut self) -> Self::Output2 {
+    fn part_two(mut self) -> aoc::Result<Self::Output2> {
         let mut air_bubbles = FxHashSet::with_capacity_and_hasher(20 * 20 * 20, <_>::default());
         let mut outer_bubbles = FxHashSet::with_capacity_and_hasher(20 * 20 * 20, <_>::default());
         for x in 0..20 {
@@ -92,41 +86,19 @@ impl Challenge for Solution {
     }
 }
 
+aoc::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "2,2,2
-1,2,2
-3,2,2
-2,1,2
-2,3,2
-2,2,1
-2,2,3
-2,2,4
-2,2,6
-1,2,5
-3,2,5
-2,1,5
-2,3,5
-";
-
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
 
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 64);
-    }
+    aoc::test_challenge!(Solution);
 
     #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 58);
+    fn bad_number() {
+        let input = "1,x,3\n4,5,6\n7,8,9\n";
+        let err = aoc::parse::<Solution>(input).unwrap_err();
+        let report = err.report(input);
+        assert!(report.starts_with("expected number at line 1, column 3"), "{report}");
     }
 }
@@ -1,7 +1,8 @@
 use std::{num::NonZeroU16, ops};
 
-use aoc::{Challenge, Parser as ChallengeParser};
-use nom::IResult;
+use aoc::{AocError, Challenge, Parser as ChallengeParser};
+use nom::{IResult, Parser};
+use parsers::{template, ParserExt};
 use pathfinding::prelude::astar;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -62,40 +63,37 @@ pub struct Solution(Vec<Blueprint>);
 
 impl ChallengeParser for Solution {
     fn parse(input: &'static str) -> IResult<&'static str, Self> {
-        let mut bp = vec![];
-        for line in input.lines() {
-            let Some((_, line)) = line.split_once("ore robot costs ") else { continue };
-            let Some((ore_cost, line)) = line.split_once(" ore. Each clay robot costs ") else { continue };
-            let Some((clay_cost, line)) = line.split_once(" ore. Each obsidian robot costs ") else { continue };
-            let Some((obsidian_cost, line)) = line.split_once(" clay. Each geode robot costs ") else { continue };
-            let Some((geode_cost, _)) = line.split_once(" obsidian.") else { continue };
-
-            let Some((obsidian_cost_ore, obsidian_cost_clay)) = obsidian_cost.split_once(" ore and ") else { continue };
-            let Some((geode_cost_ore, geode_cost_obsidian)) = geode_cost.split_once(" ore and ") else { continue };
-
-            bp.push(Blueprint {
-                ore: Vector {
-                    ore: ore_cost.parse().unwrap(),
-                    ..<_>::default()
-                },
-                clay: Vector {
-                    ore: clay_cost.parse().unwrap(),
-                    ..<_>::default()
-                },
-                obsidian: Vector {
-                    ore: obsidian_cost_ore.parse().unwrap(),
-                    clay: obsidian_cost_clay.parse().unwrap(),
-                    ..<_>::default()
-                },
-                geode: Vector {
-                    ore: geode_cost_ore.parse().unwrap(),
-                    obsidian: geode_cost_obsidian.parse().unwrap(),
-                    ..<_>::default()
-                },
-            });
-        }
-
-        Ok(("", Self(bp)))
+        template!(
+            "Blueprint {u16}: Each ore robot costs {u16} ore. \
+             Each clay robot costs {u16} ore. \
+             Each obsidian robot costs {u16} ore and {u16} clay. \
+             Each geode robot costs {u16} ore and {u16} obsidian."
+        )
+        .map(
+            |(_, ore, clay, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian)| {
+                let vector = Vector::default();
+                Blueprint {
+                    ore: Vector { ore, ..vector },
+                    clay: Vector {
+                        ore: clay,
+                        ..vector
+                    },
+                    obsidian: Vector {
+                        ore: obsidian_ore,
+                        clay: obsidian_clay,
+                        ..vector
+                    },
+                    geode: Vector {
+                        ore: geode_ore,
+                        obsidian: geode_obsidian,
+                        ..vector
+                    },
+                }
+            },
+        )
+        .lines()
+        .map(Self)
+        .parse(input)
     }
 }
 
@@ -141,7 +139,7 @@ impl State {
 }
 
 impl Blueprint {
-    fn solve(self, until: u16) -> u16 {
+    fn solve(self, until: u16) -> Option<u16> {
         // we can only build 1 robot each minute. So we don't need more production than we can use in a minute
         let max_ore_robots = u16::max(self.clay.ore, u16::max(self.obsidian.ore, self.geode.ore));
         let max_clay_robots = self.obsidian.clay;
@@ -246,10 +244,9 @@ impl Blueprint {
                 until * time_waiting - goedes
             },
             |s| s.time == until,
-        )
-        .unwrap();
+        )?;
 
-        until * until - res.1
+        Some(until * until - res.1)
     }
 }
 
@@ -257,48 +254,29 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = u16;
-    fn part_one(self) -> Self::Output1 {
+    fn part_one(self) -> aoc::Result<Self::Output1> {
         let mut sum = 0;
         for (i, bp) in self.0.into_iter().enumerate() {
-            sum += (i as u16 + 1) * bp.solve(24)
+            sum += (i as u16 + 1) * bp.solve(24).ok_or(AocError::Unsolvable)?
         }
-        sum
+        Ok(sum)
     }
 
     type Output2 = u16;
-    fn part_two(self) -> Self::Output2 {
+    fn part_two(self) -> aoc::Result<Self::Output2> {
         let mut prod = 1;
         for bp in self.0.into_iter().take(3) {
-            prod *= bp.solve(32)
+            prod *= bp.solve(32).ok_or(AocError::Unsolvable)?
         }
-        prod
+        Ok(prod)
     }
 }
 
+aoc::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
-Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.
-";
 
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
-
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 33);
-    }
-
-    #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 3472);
-    }
+    aoc::test_challenge!(Solution);
 }
@@ -1,17 +1,15 @@
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{AocError, Challenge, Parser as ChallengeParser};
 use nom::IResult;
+use parsers::{signed_number, ParserExt};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Solution(Vec<(usize, isize)>);
 
 impl ChallengeParser for Solution {
     fn parse(input: &'static str) -> IResult<&'static str, Self> {
-        let output = input
-            .lines()
-            .flat_map(|line| line.parse().ok())
-            .enumerate()
-            .collect();
-        Ok(("", Self(output)))
+        let mut numbers = signed_number::<isize>.iter_lines(input);
+        let output = numbers.by_ref().enumerate().collect();
+        Ok((numbers.finish()?, Self(output)))
     }
 }
 
@@ -19,7 +17,7 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = isize;
-    fn part_one(self) -> Self::Output1 {
+    fn part_one(self) -> aoc::Result<Self::Output1> {
         let mut mixed = self.0;
         for i in 0..mixed.len() {
             let j = mixed.iter().position(|x| x.0 == i).unwrap();
@@ -35,14 +33,16 @@ impl Challenge for Solution {
             }
         }
 
-        let Some(j) = mixed.iter().position(|x| x.1 == 0) else { return 0 };
-        mixed[(j + 1000) % mixed.len()].1
+        let Some(j) = mixed.iter().position(|x| x.1 == 0) else {
+            return Err(AocError::InvalidInput("no zero in the list"));
+        };
+        Ok(mixed[(j + 1000) % mixed.len()].1
             + mixed[(j + 2000) % mixed.len()].1
-            + mixed[(j + 3000) % mixed.len()].1
+            + mixed[(j + 3000) % mixed.len()].1)
     }
 
     type Output2 = isize;
-    fn part_two(self) -> Self::Output2 {
+    fn part_two(self) -> aoc::Result<Self::Output2> {
         let mut mixed = self.0;
         for _ in 0..10 {
             for i in 0..mixed.len() {
@@ -60,43 +60,21 @@ impl Challenge for Solution {
             }
         }
 
-        let Some(j) = mixed.iter().position(|x| x.1 == 0) else { return 0 };
-        (mixed[(j + 1000) % mixed.len()].1
+        let Some(j) = mixed.iter().position(|x| x.1 == 0) else {
+            return Err(AocError::InvalidInput("no zero in the list"));
+        };
+        Ok((mixed[(j + 1000) % mixed.len()].1
             + mixed[(j + 2000) % mixed.len()].1
             + mixed[(j + 3000) % mixed.len()].1)
-            * 811589153
+            * 811589153)
     }
 }
 
+aoc::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "1
-2
--3
-3
--2
-0
-4
-";
-
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
-
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 3);
-    }
 
-    #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 1623178306);
-    }
+    aoc::test_challenge!(Solution);
 }
@@ -1,8 +1,10 @@
-#![feature(split_array)]
-
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{AocError, Challenge, Parser as ChallengeParser};
 use fxhash::FxHashMap;
-use nom::IResult;
+use nom::{
+    error::{Error, ErrorKind},
+    Err, IResult,
+};
+use parsers::bytes::number;
 use poly::Poly;
 
 use crate::rational::Rational;
@@ -32,14 +34,25 @@ enum Expr {
 }
 
 impl ChallengeParser for Solution {
-    fn parse(input: &'static str) -> IResult<&'static str, Self> {
-        let mut monkeys = FxHashMap::with_capacity_and_hasher(input.len() / 8, Default::default());
-
-        let mut input = input.as_bytes();
+    fn parse(s: &'static str) -> IResult<&'static str, Self> {
+        let mut monkeys = FxHashMap::with_capacity_and_hasher(s.len() / 8, Default::default());
+
+        // points the error at the rest of `s`, backing up to a char boundary
+        let fail = |rest: &[u8], kind| {
+            let offset = (0..=s.len() - rest.len())
+                .rev()
+                .find(|&i| s.is_char_boundary(i))
+                .unwrap_or(0);
+            Err::Failure(Error::new(&s[offset..], kind))
+        };
+
+        let mut input = s.as_bytes();
         while input.len() > 6 {
             // assumed: all monkey names are 4 chars
             let name;
-            (name, input) = input.split_array_ref();
+            (name, input) = input
+                .split_first_chunk()
+                .ok_or_else(|| fail(input, ErrorKind::Eof))?;
             let name = *name;
 
             let op = match input.get(7) {
@@ -52,7 +65,9 @@ impl ChallengeParser for Solution {
             let res = match op {
                 Some(op) => {
                     let chunk: &[u8; 14];
-                    (chunk, input) = input.split_array_ref();
+                    (chunk, input) = input
+                        .split_first_chunk()
+                        .ok_or_else(|| fail(input, ErrorKind::Eof))?;
                     let [_, _, a, b, c, d, _, _, _, e, f, g, h, _] = *chunk;
                     let lhs = [a, b, c, d];
                     let rhs = [e, f, g, h];
@@ -60,25 +75,28 @@ impl ChallengeParser for Solution {
                 }
                 None => {
                     // assumed: input numbers are at most 4 digits to fit in u16
-                    let nl = input.iter().position(|b| *b == b'\n').unwrap();
-                    let n;
-                    (n, input) = input.split_at(nl + 1);
-                    let mut v = 0;
-                    for i in &n[2..nl] {
-                        v *= 10;
-                        v += (*i - b'0') as u16;
-                    }
+                    let rest = input
+                        .strip_prefix(b": ")
+                        .ok_or_else(|| fail(input, ErrorKind::Tag))?;
+                    let v;
+                    (input, v) = number(rest).map_err(|_| fail(rest, ErrorKind::Digit))?;
+                    input = input
+                        .strip_prefix(b"\n")
+                        .ok_or_else(|| fail(input, ErrorKind::CrLf))?;
                     Expr::Val(v)
                 }
             };
             monkeys.insert(name, res);
         }
 
-        let Expr::Val(human) = monkeys[b"humn"] else { panic!("humn wasn't an integer value") };
-        let Expr::Op(op, lhs, rhs) = monkeys[b"root"] else { panic!("root wasn't an operation") };
+        // humn must be a value and root must be an operation
+        let invalid = || fail(s.as_bytes(), ErrorKind::Verify);
+        let Some(&Expr::Val(human)) = monkeys.get(b"humn") else { return Err(invalid()) };
+        let Some(&Expr::Op(op, lhs, rhs)) = monkeys.get(b"root") else { return Err(invalid()) };
 
-        let lhs = build_poly(lhs, &monkeys);
-        let rhs = build_poly(rhs, &monkeys);
+        // every monkey that is named must be defined
+        let lhs = build_poly(lhs, &monkeys).ok_or_else(invalid)?;
+        let rhs = build_poly(rhs, &monkeys).ok_or_else(invalid)?;
 
         Ok((
             "",
@@ -92,17 +110,17 @@ impl ChallengeParser for Solution {
     }
 }
 
-fn build_poly(op: [u8; 4], equation: &FxHashMap<[u8; 4], Expr>) -> Poly {
+fn build_poly(op: [u8; 4], equation: &FxHashMap<[u8; 4], Expr>) -> Option<Poly> {
     if op == *b"humn" {
-        Poly::x()
+        Some(Poly::x())
     } else {
-        match equation[&op] {
+        match *equation.get(&op)? {
             Expr::Op(op, lhs, rhs) => {
-                let lhs = build_poly(lhs, equation);
-                let rhs = build_poly(rhs, equation);
-                Poly::apply(op, lhs, rhs)
+                let lhs = build_poly(lhs, equation)?;
+                let rhs = build_poly(rhs, equation)?;
+                Some(Poly::apply(op, lhs, rhs))
             }
-            Expr::Val(x) => Poly::from(Rational::from(x)),
+            Expr::Val(x) => Some(Poly::from(Rational::from(x))),
         }
     }
 }
@@ -122,59 +140,28 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = i64;
-    fn part_one(self) -> Self::Output1 {
+    fn part_one(self) -> aoc::Result<Self::Output1> {
         Poly::apply(self.op, self.lhs, self.rhs)
             .eval(Rational::from(self.human))
             .val()
-            .unwrap()
+            .ok_or(AocError::InvalidInput("root must be an integer"))
     }
 
     type Output2 = i64;
-    fn part_two(self) -> Self::Output2 {
+    fn part_two(self) -> aoc::Result<Self::Output2> {
         Poly::apply(Op::Sub, self.lhs, self.rhs)
             .solve()
+            .ok_or(AocError::Unsolvable)?
             .val()
-            .unwrap()
+            .ok_or(AocError::InvalidInput("humn must be an integer"))
     }
 }
 
+aoc::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "root: pppw + sjmn
-dbpl: 5
-cczh: sllz + lgvd
-zczc: 2
-ptdq: humn - dvpt
-dvpt: 3
-lfqf: 4
-humn: 5
-ljgn: 2
-sjmn: drzm * dbpl
-sllz: 4
-pppw: cczh / lfqf
-lgvd: ljgn * ptdq
-drzm: hmdt - zczc
-hmdt: 32
-";
-
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
 
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 152);
-    }
-
-    #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 301);
-    }
+    aoc::test_challenge!(Solution);
 }
@@ -206,13 +206,14 @@ impl Poly {
 
     // solve self == 0
     // (currently only works for linear polynomials)
-    pub fn solve(self) -> Rational {
+    /// solves linear polynomials. Higher order polynomials are not supported
+    pub fn solve(self) -> Option<Rational> {
         // (ax + b)*x^exp = 0
-        let [a, b] = self.mantissa.as_slice() else { todo!("higher order polynomials") };
+        let [a, b] = self.mantissa.as_slice() else { return None };
         // ax + b = 0
         if self.exponent != 0 {
-            todo!("higher order polynomials")
+            return None;
         }
-        -*b / *a
+        Some(-*b / *a)
     }
 }
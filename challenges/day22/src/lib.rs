@@ -1,36 +1,62 @@
-use std::ops::Range;
-
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{AocError, Challenge, Parser as ChallengeParser};
 use arrayvec::ArrayVec;
-use nom::{bytes::complete::tag, IResult, Parser};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_until},
+    character::complete::{char, line_ending},
+    combinator::opt,
+    error::{Error, ErrorKind},
+    Err, IResult, Parser,
+};
+use parsers::{number, Grid, ParserExt};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Solution {
-    map: &'static str,
-    sections: ArrayVec<Section, 6>,
+    /// the map, padded with spaces into a rectangle
+    map: Grid<u8>,
+    path: Vec<Step>,
 }
 
-#[derive(Debug, PartialEq, Clone)]
-struct Section {
-    offset: usize,
-    stride: usize,
-    x: Range<usize>,
-    y: Range<usize>,
-    // neighbouring sections
-    x0: usize,
-    x1: usize,
-    y0: usize,
-    y1: usize,
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Step {
+    Forward(usize),
+    Left,
+    Right,
+}
+
+/// Right, down, left and up, numbered the same as in the password
+const DIRECTIONS: [(isize, isize); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct State {
+    x: usize,
+    y: usize,
+    facing: usize,
 }
 
 impl ChallengeParser for Solution {
     fn parse(input: &'static str) -> IResult<&'static str, Self> {
-        // for line in input.lines().reduce(f) {
+        let (rest, map) = take_until("\n\n")(input)?;
+        let width = map.lines().map(str::len).max().unwrap_or_default();
+        if width == 0 {
+            return Err(Err::Error(Error::new(input, ErrorKind::Many1)));
+        }
+        let mut cells = Vec::with_capacity(width * map.lines().count());
+        for line in map.lines() {
+            cells.extend(line.bytes());
+            cells.resize(cells.len() + width - line.len(), b' ');
+        }
 
-        // }
+        let step = alt((
+            number.map(Step::Forward),
+            char('L').map(|_| Step::Left),
+            char('R').map(|_| Step::Right),
+        ));
+        let (rest, path) = step.many1().preceded_by(tag("\n\n")).parse(rest)?;
+        let (rest, _) = opt(line_ending)(rest)?;
 
-        // tag("").map(Self).parse(input)
-        todo!()
+        let map = Grid::new(cells, width);
+        Ok((rest, Self { map, path }))
     }
 }
 
@@ -38,52 +64,213 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = usize;
-    fn part_one(self) -> Self::Output1 {
-        0
+    fn part_one(self) -> aoc::Result<Self::Output1> {
+        self.walk(|state| Some(self.wrap_around(state)))
     }
 
     type Output2 = usize;
-    fn part_two(self) -> Self::Output2 {
-        0
+    fn part_two(self) -> aoc::Result<Self::Output2> {
+        let cube = Cube::fold(&self.map)
+            .ok_or(AocError::InvalidInput("the map does not fold into a cube"))?;
+        self.walk(|state| cube.wrap_around(state))
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "        ...#
-        .#..
-        #...
-        ....
-...#.......#
-........#...
-..#....#....
-..........#.
-        ...#....
-        .....#..
-        .#......
-        ......#.
-
-10R5L5R10L4R5L5
-";
-
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
+impl Solution {
+    /// Follows the path from the top left open tile, returning the password.
+    /// `wrap` gives where a step off the edge of the map lands, if it lands anywhere
+    fn walk(&self, wrap: impl Fn(State) -> Option<State>) -> aoc::Result<usize> {
+        let x = self.map.row(0).iter().position(|&b| b == b'.');
+        let x = x.ok_or(AocError::InvalidInput("the top row has no open tiles"))?;
+        let mut state = State { x, y: 0, facing: 0 };
+
+        for &step in &self.path {
+            match step {
+                Step::Left => state.facing = (state.facing + 3) % 4,
+                Step::Right => state.facing = (state.facing + 1) % 4,
+                Step::Forward(n) => {
+                    for _ in 0..n {
+                        let next = match self.step(state) {
+                            Some(next) => next,
+                            None => wrap(state).ok_or(AocError::InvalidInput(
+                                "the path walks off the edge of the map",
+                            ))?,
+                        };
+                        if self.map[(next.x, next.y)] == b'#' {
+                            break;
+                        }
+                        state = next;
+                    }
+                }
+            }
+        }
+
+        Ok(1000 * (state.y + 1) + 4 * (state.x + 1) + state.facing)
     }
 
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 6032);
+    /// The next tile in the direction faced, if it is on the map
+    fn step(&self, State { x, y, facing }: State) -> Option<State> {
+        let (dx, dy) = DIRECTIONS[facing];
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        (*self.map.get(x, y)? != b' ').then_some(State { x, y, facing })
     }
 
-    #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 0);
+    /// Wraps to the tile at the other end of the row or column
+    fn wrap_around(&self, state: State) -> State {
+        let mut edge = State {
+            facing: (state.facing + 2) % 4,
+            ..state
+        };
+        while let Some(next) = self.step(edge) {
+            edge = next;
+        }
+        State {
+            facing: state.facing,
+            ..edge
+        }
     }
 }
+
+type Vector = [i64; 3];
+
+fn neg([x, y, z]: Vector) -> Vector {
+    [-x, -y, -z]
+}
+
+fn dot(a: Vector, b: Vector) -> i64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+/// The map folded into a cube centred on the origin
+struct Cube {
+    size: usize,
+    faces: ArrayVec<Face, 6>,
+}
+
+/// Where a `size` by `size` block of the map ends up on the cube. `right` and `down`
+/// are the directions of the map's x and y axes on the face
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    block: (usize, usize),
+    normal: Vector,
+    right: Vector,
+    down: Vector,
+}
+
+impl Face {
+    /// The direction on the cube of moving with `facing` across this face
+    fn heading(&self, facing: usize) -> Vector {
+        [self.right, self.down, neg(self.right), neg(self.down)][facing]
+    }
+
+    /// The neighbouring block of the map in the direction `facing`, folded over the edge
+    fn fold(&self, facing: usize, block: (usize, usize)) -> Self {
+        let Self {
+            normal,
+            right,
+            down,
+            ..
+        } = *self;
+        let (normal, right, down) = match facing {
+            0 => (right, neg(normal), down),
+            1 => (down, right, neg(normal)),
+            2 => (neg(right), normal, down),
+            _ => (neg(down), right, normal),
+        };
+        Self {
+            block,
+            normal,
+            right,
+            down,
+        }
+    }
+}
+
+impl Cube {
+    /// Folds the six faces of the map, starting from the first one on the top row.
+    /// Returns `None` if the map is not the net of a cube
+    fn fold(map: &Grid<u8>) -> Option<Self> {
+        let tiles = map.cells().iter().filter(|&&b| b != b' ').count();
+        let size = (tiles / 6).isqrt();
+        if size == 0 || size * size * 6 != tiles {
+            return None;
+        }
+        let is_face =
+            |(bx, by): (usize, usize)| map.get(bx * size, by * size).is_some_and(|&b| b != b' ');
+
+        let first = (0..map.width() / size).find(|&bx| is_face((bx, 0)))?;
+        let mut stack = vec![Face {
+            block: (first, 0),
+            normal: [0, 0, -1],
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+        }];
+        let mut faces = ArrayVec::<Face, 6>::new();
+        while let Some(face) = stack.pop() {
+            if faces.iter().any(|f| f.block == face.block) {
+                continue;
+            }
+            faces.try_push(face).ok()?;
+
+            let (bx, by) = face.block;
+            for (facing, (dx, dy)) in DIRECTIONS.into_iter().enumerate() {
+                let (Some(bx), Some(by)) = (bx.checked_add_signed(dx), by.checked_add_signed(dy))
+                else {
+                    continue;
+                };
+                if is_face((bx, by)) {
+                    stack.push(face.fold(facing, (bx, by)));
+                }
+            }
+        }
+
+        let mut normals: Vec<_> = faces.iter().map(|face| face.normal).collect();
+        normals.sort_unstable();
+        normals.dedup();
+        (normals.len() == 6).then_some(Self { size, faces })
+    }
+
+    /// Rolls over the edge of the cube onto the next face. Returns `None` if the tile
+    /// is not on a face of the cube
+    fn wrap_around(&self, State { x, y, facing }: State) -> Option<State> {
+        let size = self.size;
+        let block = (x / size, y / size);
+        let face = self.faces.iter().find(|face| face.block == block)?;
+
+        // positions on the cube are doubled, so that the centre of each tile is a whole
+        // number and the faces are at -s and s
+        let s = size as i64;
+        let doubled = |n: usize| 2 * (n % size) as i64 + 1 - s;
+        let heading = face.heading(facing);
+        let (across, offset) = match facing % 2 {
+            0 => (face.down, doubled(y)),
+            _ => (face.right, doubled(x)),
+        };
+
+        // the heading becomes the normal of the next face. The tile just over the edge
+        // is still next to the old face, and moving on takes it away from it
+        let next = self.faces.iter().find(|face| face.normal == heading)?;
+        let position = |axis: Vector| {
+            let doubled = dot(heading, axis) * s
+                + dot(face.normal, axis) * (s - 1)
+                + dot(across, axis) * offset;
+            ((doubled + s - 1) / 2) as usize
+        };
+        let (bx, by) = next.block;
+        Some(State {
+            x: bx * size + position(next.right),
+            y: by * size + position(next.down),
+            facing: (0..4).find(|&facing| next.heading(facing) == neg(face.normal))?,
+        })
+    }
+}
+
+aoc::register!(Solution);
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+
+    aoc::test_challenge!(Solution);
+}
@@ -1,4 +1,3 @@
-#![feature(map_entry_replace)]
 use std::collections::hash_map::Entry;
 
 use aoc::{Challenge, Parser as ChallengeParser};
@@ -134,44 +133,21 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = usize;
-    fn part_one(self) -> Self::Output1 {
-        self.0
+    fn part_one(self) -> aoc::Result<Self::Output1> {
+        Ok(self.0)
     }
 
     type Output2 = usize;
-    fn part_two(self) -> Self::Output2 {
-        self.1
+    fn part_two(self) -> aoc::Result<Self::Output2> {
+        Ok(self.1)
     }
 }
 
+aoc::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "....#..
-..###.#
-#...#.#
-.#...##
-#.###..
-##.#.##
-.#..#..";
-
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
 
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 110);
-    }
-
-    #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 20);
-    }
+    aoc::test_challenge!(Solution);
 }
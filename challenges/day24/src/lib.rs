@@ -1,5 +1,8 @@
-use aoc::{Challenge, Parser as ChallengeParser};
-use nom::IResult;
+use aoc::{AocError, Challenge, Parser as ChallengeParser};
+use nom::{
+    error::{Error, ErrorKind},
+    Err, IResult,
+};
 use pathfinding::prelude::astar;
 
 const N: u8 = b'^';
@@ -7,8 +10,9 @@ const E: u8 = b'>';
 const S: u8 = b'v';
 const W: u8 = b'<';
 
+/// The minutes taken to reach the goal, and to go back for the snacks and return
 #[derive(Debug, PartialEq, Clone)]
-pub struct Solution(usize, usize);
+pub struct Solution(aoc::Result<(usize, usize)>);
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Input(&'static [u8], usize);
@@ -17,36 +21,14 @@ impl ChallengeParser for Solution {
     fn parse(input: &'static str) -> IResult<&'static str, Self> {
         // let mut state = Vec::with_capacity(input.len());
 
-        let row_len = input.bytes().position(|b| b == b'\n').unwrap() + 1;
+        let row_len = input
+            .bytes()
+            .position(|b| b == b'\n')
+            .ok_or_else(|| Err::Failure(Error::new(input, ErrorKind::TakeUntil)))?
+            + 1;
         let i = Input(input.as_bytes(), row_len);
-        let column_len = input.len() / row_len;
 
-        let x = i.solve(
-            State {
-                minute: 0,
-                x: 1,
-                y: 0,
-            },
-            (row_len - 3, column_len - 1),
-        );
-        let y = i.solve(
-            State {
-                minute: x,
-                x: row_len - 3,
-                y: column_len - 1,
-            },
-            (1, 0),
-        );
-        let z = i.solve(
-            State {
-                minute: x + y,
-                x: 1,
-                y: 0,
-            },
-            (row_len - 3, column_len - 1),
-        );
-
-        Ok(("", Self(x, x + y + z)))
+        Ok(("", Self(i.trips())))
     }
 }
 
@@ -54,16 +36,18 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = usize;
-    fn part_one(self) -> Self::Output1 {
-        self.0
+    fn part_one(self) -> aoc::Result<Self::Output1> {
+        self.0.map(|(there, _)| there)
     }
 
     type Output2 = usize;
-    fn part_two(self) -> Self::Output2 {
-        self.1
+    fn part_two(self) -> aoc::Result<Self::Output2> {
+        self.0.map(|(_, back_again)| back_again)
     }
 }
 
+aoc::register!(Solution);
+
 #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 struct State {
     minute: usize,
@@ -72,7 +56,29 @@ struct State {
 }
 
 impl Input {
-    fn solve(&self, start: State, goal: (usize, usize)) -> usize {
+    fn trips(&self) -> aoc::Result<(usize, usize)> {
+        let Self(state, row_len) = *self;
+        let column_len = state.len() / row_len;
+        if row_len < 4 || column_len < 3 {
+            return Err(AocError::InvalidInput(
+                "the valley needs at least one row and column inside its walls",
+            ));
+        }
+
+        let start = (1, 0);
+        let end = (row_len - 3, column_len - 1);
+        let trip = |minute, (x, y), goal| {
+            self.solve(State { minute, x, y }, goal)
+                .ok_or(AocError::Unsolvable)
+        };
+
+        let x = trip(0, start, end)?;
+        let y = trip(x, end, start)?;
+        let z = trip(x + y, start, end)?;
+        Ok((x, x + y + z))
+    }
+
+    fn solve(&self, start: State, goal: (usize, usize)) -> Option<usize> {
         let Self(state, row_len) = *self;
         let column_len = state.len() / row_len;
 
@@ -125,39 +131,22 @@ impl Input {
             |s| (goal.0).abs_diff(s.x) + (goal.1).abs_diff(s.y),
             |s| s.x == goal.0 && s.y == goal.1,
         )
-        .unwrap()
-        .1
+        .map(|(_, minutes)| minutes)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "#.######
-#>>.<^<#
-#.<..<<#
-#>v.><>#
-#<^v^^>#
-######.#
-";
 
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
-
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 18);
-    }
+    aoc::test_challenge!(Solution);
 
     #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 54);
+    fn too_small() {
+        use aoc::{AocError, Challenge, Parser};
+        let (_, solution) = Solution::parse("#.#\n").unwrap();
+        let error =
+            AocError::InvalidInput("the valley needs at least one row and column inside its walls");
+        assert_eq!(solution.part_one(), Err(error));
     }
 }
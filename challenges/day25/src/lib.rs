@@ -2,20 +2,19 @@ use core::fmt;
 use std::{convert::Infallible, str::FromStr};
 
 use aoc::{Challenge, Parser as ChallengeParser};
-use nom::IResult;
+use nom::{bytes::complete::is_a, IResult};
+use parsers::ParserExt;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Solution(Snafu);
 
 impl ChallengeParser for Solution {
     fn parse(input: &'static str) -> IResult<&'static str, Self> {
-        let n = input
-            .lines()
-            .map(|line| line.parse::<Snafu>().unwrap())
-            .reduce(|a, b| Snafu(a.0 + b.0))
-            .unwrap();
+        // `iter_lines` needs at least one number, so `finish` fails on an empty input
+        let mut numbers = is_a("210-=").map_res(str::parse::<Snafu>).iter_lines(input);
+        let n = numbers.by_ref().fold(Snafu(0), |a, b| Snafu(a.0 + b.0));
 
-        Ok(("", Self(n)))
+        Ok((numbers.finish()?, Self(n)))
     }
 }
 
@@ -65,51 +64,35 @@ impl Challenge for Solution {
     const NAME: &'static str = env!("CARGO_PKG_NAME");
 
     type Output1 = Snafu;
-    fn part_one(self) -> Self::Output1 {
-        self.0
+    fn part_one(self) -> aoc::Result<Self::Output1> {
+        Ok(self.0)
     }
 
     type Output2 = usize;
-    fn part_two(self) -> Self::Output2 {
-        0
+    fn part_two(self) -> aoc::Result<Self::Output2> {
+        Ok(0)
     }
 }
 
+aoc::register!(Solution);
+
 #[cfg(test)]
 mod tests {
-    use super::Solution;
-    use aoc::{Challenge, Parser};
-
-    const INPUT: &str = "1=-0-2
-12111
-2=0=
-21
-2=01
-111
-20012
-112
-1=-1=
-1-12
-12
-1=
-122
-";
+    use aoc::AocError;
+    use nom::error::ErrorKind;
 
-    #[test]
-    fn parse() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        println!("{output:?}");
-    }
+    use super::Solution;
 
-    #[test]
-    fn part_one() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one().to_string(), "2=-1=0");
-    }
+    aoc::test_challenge!(Solution);
 
     #[test]
-    fn part_two() {
-        let output = Solution::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 0);
+    fn bad_first_line() {
+        let error = |input| match aoc::parse::<Solution>(input) {
+            Err(AocError::Parse { offset, kind }) => Some((offset, kind)),
+            _ => None,
+        };
+        assert_eq!(error(""), Some((0, ErrorKind::IsA)));
+        assert_eq!(error("x\n1=\n"), Some((0, ErrorKind::IsA)));
+        assert_eq!(error("1=\n"), None);
     }
 }
@@ -0,0 +1,145 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Attribute, Data, DeriveInput, Error, Expr, Fields, LitStr, Result};
+
+/// The literal text matched before and between the fields of a type
+#[derive(Default)]
+struct Literals {
+    prefix: Option<LitStr>,
+    separator: Option<LitStr>,
+}
+
+impl Literals {
+    fn new(attrs: &[Attribute]) -> Result<Self> {
+        let mut literals = Self::default();
+        for attr in attrs {
+            if attr.path().is_ident("prefix") {
+                literals.prefix = Some(attr.parse_args()?);
+            } else if attr.path().is_ident("separator") {
+                literals.separator = Some(attr.parse_args()?);
+            } else if attr.path().is_ident("tag") || attr.path().is_ident("with") {
+                let message = "`#[tag]` goes on enum variants and `#[with]` on fields";
+                return Err(Error::new_spanned(attr, message));
+            }
+        }
+        Ok(literals)
+    }
+}
+
+fn tag(literal: &LitStr) -> TokenStream {
+    quote! {
+        let (input, _) = ::parsers::__nom::bytes::complete::tag::<
+            _,
+            _,
+            ::parsers::__nom::error::Error<&str>,
+        >(#literal)(input)?;
+    }
+}
+
+/// Parses each field in order, then builds `constructor` out of them
+fn fields(
+    fields: &Fields,
+    separator: Option<&LitStr>,
+    constructor: TokenStream,
+) -> Result<TokenStream> {
+    let mut steps = vec![];
+    let mut names = vec![];
+    for (i, field) in fields.iter().enumerate() {
+        let mut parser = quote!(::parsers::number);
+        for attr in &field.attrs {
+            if attr.path().is_ident("prefix") {
+                steps.push(tag(&attr.parse_args()?));
+            } else if attr.path().is_ident("with") {
+                let with: Expr = attr.parse_args()?;
+                parser = quote!((#with));
+            } else if attr.path().is_ident("tag") || attr.path().is_ident("separator") {
+                let message = "only `#[prefix]` and `#[with]` can go on fields";
+                return Err(Error::new_spanned(attr, message));
+            }
+        }
+
+        let name = format_ident!("field{i}");
+        steps.push(quote! {
+            let (input, #name) = ::parsers::__nom::Parser::parse(&mut #parser, input)?;
+        });
+        names.push(name);
+
+        if let Some(separator) = separator.filter(|_| i + 1 < fields.len()) {
+            steps.push(tag(separator));
+        }
+    }
+
+    let value = match fields {
+        Fields::Named(named) => {
+            let idents = named.named.iter().map(|field| &field.ident);
+            quote!(#constructor { #(#idents: #names),* })
+        }
+        Fields::Unnamed(_) => quote!(#constructor(#(#names),*)),
+        Fields::Unit => constructor,
+    };
+
+    Ok(quote! {
+        #(#steps)*
+        ::std::result::Result::Ok((input, #value))
+    })
+}
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let literals = Literals::new(&input.attrs)?;
+    let separator = literals.separator.as_ref();
+
+    let body = match &input.data {
+        Data::Struct(data) => fields(&data.fields, separator, quote!(Self))?,
+        Data::Enum(data) => {
+            let mut variants = vec![];
+            for variant in &data.variants {
+                let mut tag_literal = None;
+                for attr in &variant.attrs {
+                    if attr.path().is_ident("tag") {
+                        tag_literal = Some(tag(&attr.parse_args()?));
+                    } else if attr.path().is_ident("prefix") || attr.path().is_ident("separator") {
+                        let message = "use `#[tag]` to match the start of a variant";
+                        return Err(Error::new_spanned(attr, message));
+                    }
+                }
+
+                let ident = &variant.ident;
+                let parse = fields(&variant.fields, separator, quote!(Self::#ident))?;
+                variants.push(quote! {
+                    let result: ::parsers::__nom::IResult<&str, Self> = (|| {
+                        #tag_literal
+                        #parse
+                    })();
+                    match result {
+                        ::std::result::Result::Err(::parsers::__nom::Err::Error(_)) => {}
+                        result => return result,
+                    }
+                });
+            }
+            quote! {
+                #(#variants)*
+                ::std::result::Result::Err(::parsers::__nom::Err::Error(
+                    ::parsers::__nom::error::Error::new(input, ::parsers::__nom::error::ErrorKind::Alt),
+                ))
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                input,
+                "`AocParse` cannot be derived for unions",
+            ))
+        }
+    };
+
+    let prefix = literals.prefix.as_ref().map(tag);
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::parsers::AocParse for #ident #ty_generics #where_clause {
+            fn parse(input: &str) -> ::parsers::__nom::IResult<&str, Self> {
+                #prefix
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,25 @@
+//! Procedural macros for `parsers`. Use them through the re-exports in that crate
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, LitStr};
+
+mod derive;
+mod template;
+
+/// See `parsers::template!`
+#[proc_macro]
+pub fn template(input: TokenStream) -> TokenStream {
+    let format = parse_macro_input!(input as LitStr);
+    template::expand(&format)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// See `parsers::AocParse`
+#[proc_macro_derive(AocParse, attributes(prefix, separator, tag, with))]
+pub fn derive_aoc_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,93 @@
+use std::mem;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Error, LitStr, Result, Type};
+
+enum Piece {
+    Literal(String),
+    Field(Type),
+}
+
+/// Splits the format into the literal text and the `{Type}` placeholders.
+/// `{{` and `}}` match a literal brace
+fn pieces(format: &LitStr) -> Result<Vec<Piece>> {
+    let value = format.value();
+    let error = |message: String| Error::new(format.span(), message);
+
+    let mut pieces = vec![];
+    let mut literal = String::new();
+    let mut rest = value.as_str();
+    while let Some(c) = rest.chars().next() {
+        rest = &rest[c.len_utf8()..];
+        match c {
+            '{' | '}' if rest.starts_with(c) => {
+                rest = &rest[1..];
+                literal.push(c);
+            }
+            '{' => {
+                let end = rest
+                    .find('}')
+                    .ok_or_else(|| error("unclosed `{` in template".to_owned()))?;
+                let ty = syn::parse_str(&rest[..end])
+                    .map_err(|err| error(format!("invalid type `{}`: {err}", &rest[..end])))?;
+                rest = &rest[end + 1..];
+
+                if !literal.is_empty() {
+                    pieces.push(Piece::Literal(mem::take(&mut literal)));
+                }
+                pieces.push(Piece::Field(ty));
+            }
+            '}' => {
+                return Err(error(
+                    "unmatched `}` in template, use `}}` to match one".to_owned(),
+                ))
+            }
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        pieces.push(Piece::Literal(literal));
+    }
+    Ok(pieces)
+}
+
+pub fn expand(format: &LitStr) -> Result<TokenStream> {
+    let mut steps = vec![];
+    let mut types = vec![];
+    let mut fields = vec![];
+    for piece in pieces(format)? {
+        steps.push(match piece {
+            Piece::Literal(literal) => quote! {
+                let (input, _) = ::parsers::__nom::bytes::complete::tag::<
+                    _,
+                    _,
+                    ::parsers::__nom::error::Error<&'a str>,
+                >(#literal)(input)?;
+            },
+            Piece::Field(ty) => {
+                let field = format_ident!("field{}", fields.len());
+                let step = quote! {
+                    let (input, #field) = <#ty as ::parsers::Field<'a>>::field(input)?;
+                };
+                types.push(ty);
+                fields.push(field);
+                step
+            }
+        });
+    }
+
+    let (output, value) = match (&*types, &*fields) {
+        ([ty], [field]) => (quote!(#ty), quote!(#field)),
+        _ => (quote!((#(#types,)*)), quote!((#(#fields,)*))),
+    };
+
+    Ok(quote! {{
+        #[allow(clippy::needless_lifetimes)]
+        fn template<'a>(input: &'a str) -> ::parsers::__nom::IResult<&'a str, #output> {
+            #(#steps)*
+            ::std::result::Result::Ok((input, #value))
+        }
+        template
+    }})
+}
@@ -0,0 +1,174 @@
+//! Versions of the parsers for `&[u8]` input, for the days that work on raw bytes
+
+use nom::{
+    character::complete::line_ending,
+    error::{Error, ErrorKind, ParseError},
+    Err, IResult, Parser,
+};
+
+use crate::{grid::Grid, ParserExt};
+
+const ONES: u64 = u64::from_ne_bytes([0x01; 8]);
+
+/// Loads up to 8 bytes so that the first byte is the lowest, padding with zeros
+fn load(input: &[u8]) -> u64 {
+    let mut chunk = [0; 8];
+    let len = input.len().min(8);
+    chunk[..len].copy_from_slice(&input[..len]);
+    u64::from_le_bytes(chunk)
+}
+
+/// Turns the ascii digits in `chunk` into their values, returning how many
+/// of the bytes at the start were digits
+fn digits(chunk: u64) -> (u64, usize) {
+    // only the bytes '0'..='9' become 0..=9
+    let values = chunk ^ (ONES * b'0' as u64);
+    // sets the high bit of each byte that is not below 10,
+    // masking the high bits first so that the add can't carry into the next byte
+    let non_digits = (((values & (ONES * 0x7f)) + ONES * (0x80 - 10)) | values) & (ONES * 0x80);
+    (values, non_digits.trailing_zeros() as usize / 8)
+}
+
+/// Combines the first `len` digit values of a chunk into a number, all at once
+fn combine(values: u64, len: usize) -> u64 {
+    if len == 0 {
+        return 0;
+    }
+    // shift the digits to the end, leaving leading zeros
+    let v = values << (8 * (8 - len));
+    let v = (v.wrapping_mul(10) + (v >> 8)) & 0x00ff_00ff_00ff_00ff;
+    let v = (v.wrapping_mul(100) + (v >> 16)) & 0x0000_ffff_0000_ffff;
+    (v.wrapping_mul(10_000) + (v >> 32)) & 0x0000_0000_ffff_ffff
+}
+
+/// Parses a decimal number 8 digits at a time, like [`number`](crate::number)
+///
+/// ```
+/// assert_eq!(parsers::bytes::number::<u32>(b"1234 5"), Ok((&b" 5"[..], 1234)));
+/// assert_eq!(parsers::bytes::number::<u64>(b"12345678901"), Ok((&b""[..], 12345678901)));
+/// assert!(parsers::bytes::number::<u8>(b"256").is_err());
+/// assert!(parsers::bytes::number::<u8>(b"x").is_err());
+/// ```
+pub fn number<O>(input: &[u8]) -> IResult<&[u8], O>
+where
+    O: TryFrom<u64>,
+{
+    let mut n = 0u64;
+    let mut rest = input;
+    loop {
+        let (values, len) = digits(load(rest));
+        if len == 0 && rest.len() == input.len() {
+            return Err(Err::Error(Error::new(input, ErrorKind::Digit)));
+        }
+        n = n
+            .checked_mul(10u64.pow(len as u32))
+            .and_then(|n| n.checked_add(combine(values, len)))
+            .ok_or(Err::Error(Error::new(input, ErrorKind::MapRes)))?;
+        rest = &rest[len..];
+        if len < 8 {
+            break;
+        }
+    }
+    match O::try_from(n) {
+        Ok(n) => Ok((rest, n)),
+        Err(_) => Err(Err::Error(Error::new(input, ErrorKind::MapRes))),
+    }
+}
+
+/// ```
+/// assert_eq!(parsers::bytes::binary(b"0110\n"), Ok((&b"\n"[..], 6)));
+/// ```
+pub fn binary(input: &[u8]) -> IResult<&[u8], usize> {
+    let len = input
+        .iter()
+        .take_while(|b| matches!(b, b'0' | b'1'))
+        .count();
+    if len == 0 || len > usize::BITS as usize {
+        return Err(Err::Error(Error::new(input, ErrorKind::IsA)));
+    }
+    let (digits, rest) = input.split_at(len);
+    let n = digits
+        .iter()
+        .fold(0, |n, &b| n << 1 | usize::from(b - b'0'));
+    Ok((rest, n))
+}
+
+pub fn lines<'a, O, E, F>(f: F) -> impl Parser<&'a [u8], Vec<O>, E>
+where
+    F: Parser<&'a [u8], O, E>,
+    E: ParseError<&'a [u8]>,
+{
+    f.separated_list1(line_ending)
+}
+
+pub fn grid<'a, O, E, F>(f: F) -> impl Parser<&'a [u8], Vec<Vec<O>>, E>
+where
+    F: Parser<&'a [u8], O, E>,
+    E: ParseError<&'a [u8]>,
+{
+    f.many1().separated_list1(line_ending)
+}
+
+/// Like [`byte_grid`](crate::byte_grid)
+///
+/// ```
+/// let (rest, grid) = parsers::bytes::byte_grid(b"#.\n.#\n").unwrap();
+/// assert_eq!((rest, grid.width(), grid.height()), (&b""[..], 2, 2));
+/// ```
+pub fn byte_grid(input: &'static [u8]) -> IResult<&'static [u8], Grid<u8>> {
+    let line_len = |s: &[u8]| s.iter().position(|b| matches!(b, b'\r' | b'\n'));
+    let line_len = |s: &[u8]| line_len(s).unwrap_or(s.len());
+
+    let width = line_len(input);
+    if width == 0 {
+        return Err(Err::Error(Error::new(input, ErrorKind::Many1)));
+    }
+    let stride = match input[width..].starts_with(b"\r\n") {
+        true => width + 2,
+        false => width + 1,
+    };
+
+    let mut height = 0;
+    let mut rest = input;
+    while !rest.is_empty() {
+        match line_len(rest) {
+            0 => break,
+            len if len == width => height += 1,
+            _ => return Err(Err::Error(Error::new(rest, ErrorKind::Verify))),
+        }
+        match line_ending::<_, Error<_>>(&rest[width..]) {
+            Ok((next, _)) => rest = next,
+            Err(_) => {
+                rest = &rest[width..];
+                break;
+            }
+        }
+    }
+
+    let cells = &input[..input.len() - rest.len()];
+    Ok((rest, Grid::borrowed(cells, width, height, stride)))
+}
+
+fn split_once<'a>(s: &'a [u8], delimiter: &[u8]) -> Option<(&'a [u8], &'a [u8])> {
+    let i = s
+        .windows(delimiter.len())
+        .position(|window| window == delimiter)?;
+    Some((&s[..i], &s[i + delimiter.len()..]))
+}
+
+/// ```
+/// let line = b"move 1 from 2 to 3";
+/// let segments: [&[u8]; 3] = [b"move ", b" from ", b" to "];
+/// assert_eq!(parsers::bytes::split_many(line, segments), Some([&b"1"[..], b"2", b"3"]));
+/// ```
+pub fn split_many<'a, const N: usize>(
+    mut s: &'a [u8],
+    mut delimiters: [&'a [u8]; N],
+) -> Option<[&'a [u8]; N]> {
+    s = s.strip_prefix(delimiters[0])?;
+    for i in 1..N {
+        (delimiters[i - 1], s) = split_once(s, delimiters[i])?;
+    }
+    delimiters[N - 1] = s;
+    Some(delimiters)
+}
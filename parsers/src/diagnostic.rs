@@ -0,0 +1,131 @@
+//! Human readable reports for parse failures
+//!
+//! ```
+//! use nom::Finish;
+//! use parsers::{diagnostic::Diagnostic, number};
+//!
+//! let input = "move 1 from 2 to 1\nmove x from 1 to 3\n";
+//! let err = nom::sequence::preceded(
+//!     nom::bytes::complete::tag("move "),
+//!     number::<u32>,
+//! )(&input[19..])
+//! .finish()
+//! .unwrap_err();
+//!
+//! let diagnostic = Diagnostic::from_error(input, err);
+//! assert_eq!((diagnostic.line, diagnostic.column), (2, 6));
+//! assert_eq!(
+//!     diagnostic.to_string(),
+//!     "expected number at line 2, column 6\n  |\n2 | move x from 1 to 3\n  |      ^\n"
+//! );
+//! ```
+
+use std::fmt;
+
+use nom::error::{Error, ErrorKind};
+
+/// Where a parser failed and what it was looking for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic<'a> {
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in characters
+    pub column: usize,
+    /// the line the parser failed on, without its line ending
+    pub source: &'a str,
+    pub kind: ErrorKind,
+    /// whether the parser failed at the end of the input
+    pub at_end: bool,
+}
+
+impl<'a> Diagnostic<'a> {
+    /// `offset` is the byte offset into `input` where the parser failed
+    pub fn new(input: &'a str, offset: usize, kind: ErrorKind) -> Self {
+        let offset = floor_char_boundary(input, offset.min(input.len()));
+        let (before, after) = input.split_at(offset);
+        let start = before.rfind('\n').map_or(0, |i| i + 1);
+        let rest = after.split('\n').next().unwrap_or_default();
+        let source = &input[start..offset + rest.len()];
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[start..].chars().count() + 1,
+            source: source.strip_suffix('\r').unwrap_or(source),
+            kind,
+            at_end: after.is_empty(),
+        }
+    }
+
+    /// What the parser was expecting, eg "number" for [`crate::number`]
+    pub fn expected(&self) -> &str {
+        match self.kind {
+            // nom's `eof` fails when there is input left, but a failure at the
+            // very end means that the parser needed more
+            ErrorKind::Eof if self.at_end => "more input",
+            kind => expected(kind).unwrap_or_else(|| self.kind.description()),
+        }
+    }
+
+    /// Finds where the error happened from the input that was left when the parser failed
+    pub fn from_error(input: &'a str, err: Error<&str>) -> Self {
+        let offset = input.len().saturating_sub(err.input.len());
+        Self::new(input, offset, err.code)
+    }
+
+    /// Like [`Diagnostic::from_error`], treating incomplete input as a failure at the end
+    pub fn from_nom(input: &'a str, err: nom::Err<Error<&str>>) -> Self {
+        match err {
+            nom::Err::Incomplete(_) => Self::new(input, input.len(), ErrorKind::Eof),
+            nom::Err::Error(err) | nom::Err::Failure(err) => Self::from_error(input, err),
+        }
+    }
+}
+
+fn expected(kind: ErrorKind) -> Option<&'static str> {
+    let expected = match kind {
+        ErrorKind::Tag => "specific text",
+        ErrorKind::Char | ErrorKind::OneOf | ErrorKind::NoneOf | ErrorKind::Satisfy => {
+            "a different character"
+        }
+        ErrorKind::Digit => "number",
+        ErrorKind::HexDigit => "hexadecimal number",
+        ErrorKind::OctDigit => "octal number",
+        ErrorKind::IsA => "one of the allowed characters",
+        ErrorKind::Alpha => "letter",
+        ErrorKind::AlphaNumeric => "letter or digit",
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace",
+        ErrorKind::CrLf => "line ending",
+        ErrorKind::Eof => "end of input",
+        ErrorKind::TooLarge => "smaller number",
+        ErrorKind::MapRes | ErrorKind::MapOpt | ErrorKind::Verify => "valid value",
+        ErrorKind::TakeUntil | ErrorKind::IsNot => "delimiter",
+        ErrorKind::Many1 | ErrorKind::Many1Count | ErrorKind::SeparatedList => "at least one item",
+        ErrorKind::Count | ErrorKind::ManyMN => "more items",
+        ErrorKind::Alt | ErrorKind::Permutation => "one of the alternatives",
+        _ => return None,
+    };
+    Some(expected)
+}
+
+fn floor_char_boundary(s: &str, mut index: usize) -> usize {
+    while !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+impl fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = self.line.to_string().len();
+        writeln!(
+            f,
+            "expected {} at line {}, column {}",
+            self.expected(),
+            self.line,
+            self.column
+        )?;
+        writeln!(f, "{:gutter$} |", "")?;
+        writeln!(f, "{} | {}", self.line, self.source)?;
+        writeln!(f, "{:gutter$} | {:>column$}", "", "^", column = self.column)
+    }
+}
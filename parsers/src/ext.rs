@@ -3,13 +3,17 @@ use std::{
     ops::{Range, RangeFrom, RangeTo},
 };
 
+use next_gen::mk_gen;
 use nom::{
     character::complete::line_ending,
     error::{FromExternalError, ParseError},
     Compare, IResult, InputIter, InputLength, Parser, Slice,
 };
 
+use crate::gen::{separated_list1, terminated_list1};
+
 pub use self::{
+    iter::ParseIter,
     map_res::MapRes,
     separated_array::Array,
     separated_array::SeperatedArray,
@@ -20,6 +24,7 @@ pub use self::{
     terminated_list::TerminatedList1,
 };
 
+mod iter;
 mod map_res;
 mod separated_array;
 mod separated_list;
@@ -88,6 +93,58 @@ pub trait ParserExt<I, O, E>: Parser<I, O, E> {
         }
     }
 
+    /// Parses `self` separated by `g` lazily, yielding each item as it is parsed.
+    /// Like [`separated_list1`](ParserExt::separated_list1), the first item is required
+    ///
+    /// ```
+    /// use nom::bytes::complete::tag;
+    /// use parsers::{number, ParserExt};
+    ///
+    /// let mut iter = number::<u32>.iter_separated(tag(","), "1,2,3;4");
+    /// assert_eq!(iter.by_ref().sum::<u32>(), 6);
+    /// assert_eq!(iter.finish(), Ok(";4"));
+    /// ```
+    fn iter_separated<'a, G, O2>(self, g: G, input: I) -> ParseIter<'a, I, O, E>
+    where
+        Self: Sized + 'a,
+        G: Parser<I, O2, E> + 'a,
+        I: Clone + InputLength + 'a,
+        O: 'a,
+        O2: 'a,
+        E: ParseError<I> + 'a,
+    {
+        mk_gen!(let gen = box separated_list1(input, self, g));
+        ParseIter::new(gen)
+    }
+
+    /// Parses `self` lazily on each line, like [`lines`](ParserExt::lines).
+    /// The first line is required, like [`iter_separated`](ParserExt::iter_separated).
+    /// Stops at the next line that `self` can't parse, and the last line ending is optional
+    ///
+    /// ```
+    /// use parsers::{number, ParserExt};
+    ///
+    /// let mut iter = number::<u32>.iter_lines("1\n2\n\n3\n");
+    /// assert_eq!(iter.by_ref().max(), Some(2));
+    /// assert_eq!(iter.finish(), Ok("\n3\n"));
+    ///
+    /// let mut iter = number::<u32>.iter_lines("\n3\n");
+    /// assert_eq!(iter.next(), None);
+    /// assert!(iter.finish().is_err());
+    /// ```
+    fn iter_lines<'a>(self, input: I) -> ParseIter<'a, I, O, E>
+    where
+        Self: Sized + 'a,
+        I: Slice<Range<usize>> + Slice<RangeFrom<usize>> + Slice<RangeTo<usize>>,
+        I: InputIter + InputLength + Clone + 'a,
+        I: Compare<&'static str>,
+        O: 'a,
+        E: ParseError<I> + 'a,
+    {
+        mk_gen!(let gen = box terminated_list1(input, self, line_ending));
+        ParseIter::new(gen)
+    }
+
     fn terminate_list1<G, O2, C>(self, g: G) -> TerminatedList1<Self, G, O, O2, C>
     where
         G: Parser<I, O2, E>,
@@ -0,0 +1,44 @@
+use std::pin::Pin;
+
+use next_gen::generator::{Generator, GeneratorState};
+use nom::Err;
+
+type Gen<'a, I, O, E> = Pin<Box<dyn Generator<(), Yield = O, Return = Result<I, Err<E>>> + 'a>>;
+
+/// An iterator that parses each item as it is needed.
+/// Made by [`iter_separated`](crate::ParserExt::iter_separated) and
+/// [`iter_lines`](crate::ParserExt::iter_lines)
+pub struct ParseIter<'a, I, O, E> {
+    gen: Gen<'a, I, O, E>,
+    result: Option<Result<I, Err<E>>>,
+}
+
+impl<'a, I, O, E> ParseIter<'a, I, O, E> {
+    pub(crate) fn new(gen: Gen<'a, I, O, E>) -> Self {
+        Self { gen, result: None }
+    }
+
+    /// Parses any items that have not been read yet, then returns the input that
+    /// was left after the last item, or the error that stopped the parser
+    pub fn finish(mut self) -> Result<I, Err<E>> {
+        self.by_ref().for_each(drop);
+        self.result.expect("the generator returned")
+    }
+}
+
+impl<I, O, E> Iterator for ParseIter<'_, I, O, E> {
+    type Item = O;
+
+    fn next(&mut self) -> Option<O> {
+        if self.result.is_some() {
+            return None;
+        }
+        match self.gen.as_mut().resume(()) {
+            GeneratorState::Yielded(item) => Some(item),
+            GeneratorState::Returned(result) => {
+                self.result = Some(result);
+                None
+            }
+        }
+    }
+}
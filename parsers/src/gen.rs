@@ -57,6 +57,40 @@ where
     }
 }
 
+#[generator(yield(O))]
+/// parses [f, g, f, g, f, (g)], stopping at the first f that fails after the first one.
+/// the last g is optional
+pub fn terminated_list1<I, O, O2, F, G, E>(mut input: I, mut f: F, mut g: G) -> Result<I, Err<E>>
+where
+    I: Clone + InputLength,
+    F: Parser<I, O, E>,
+    G: Parser<I, O2, E>,
+    E: ParseError<I>,
+{
+    let mut first = true;
+    loop {
+        let o;
+        (input, o) = match f.parse(input.clone()) {
+            Ok(ok) => ok,
+            Err(Err::Error(_)) if !first => return Ok(input),
+            Err(e) => return Err(e),
+        };
+        first = false;
+        yield_!(o);
+
+        let len = input.input_len();
+        input = match g.parse(input.clone()) {
+            // infinite loop check: the separator must always consume
+            Ok((i, _)) if i.input_len() == len => {
+                return Err(Err::Error(E::from_error_kind(i, ErrorKind::SeparatedList)))
+            }
+            Ok((i, _)) => i,
+            Err(Err::Error(_)) => return Ok(input),
+            Err(e) => return Err(e),
+        };
+    }
+}
+
 #[generator(yield((O, O)))]
 // parses [f, g, f, g, f, g, f] and returns each consecutive pair of f. like separated_list1 but requires at least 2 f parses
 pub fn separated_pairs<I, O, O2, F, G, E>(mut input: I, mut f: F, mut g: G) -> Result<I, Err<E>>
@@ -0,0 +1,276 @@
+use std::ops::{Deref, Index};
+
+use nom::{
+    character::complete::line_ending,
+    error::{Error, ErrorKind, ParseError},
+    Err, IResult, Parser,
+};
+
+/// A rectangular grid stored in one flat buffer, row after row.
+///
+/// Rows start every `stride` cells. A grid parsed by [`byte_grid`] borrows the input,
+/// so its stride includes the line endings between the rows.
+///
+/// ```
+/// let (_, grid) = parsers::byte_grid("abc\ndef\n").unwrap();
+/// assert_eq!((grid.width(), grid.height(), grid.stride()), (3, 2, 4));
+/// assert_eq!(grid[(1, 1)], b'e');
+/// assert_eq!(grid.index(1, 1), 5);
+/// assert_eq!(grid.coords(5), (1, 1));
+/// assert_eq!(grid.get(3, 0), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T: 'static> {
+    cells: Cells<T>,
+    width: usize,
+    height: usize,
+    stride: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Cells<T: 'static> {
+    Borrowed(&'static [T]),
+    Owned(Vec<T>),
+}
+
+impl<T> Deref for Cells<T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        match self {
+            Cells::Borrowed(cells) => cells,
+            Cells::Owned(cells) => cells,
+        }
+    }
+}
+
+const NEIGHBOURS4: [(isize, isize); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+const NEIGHBOURS8: [(isize, isize); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+
+impl<T> Grid<T> {
+    /// Makes a grid out of `cells`, with a row starting every `width` cells
+    pub fn new(cells: Vec<T>, width: usize) -> Self {
+        assert!(width > 0, "grid must have a width");
+        let height = cells.len() / width;
+        assert_eq!(cells.len(), width * height, "grid must be rectangular");
+        Self {
+            cells: Cells::Owned(cells),
+            width,
+            height,
+            stride: width,
+        }
+    }
+
+    pub(crate) fn borrowed(
+        cells: &'static [T],
+        width: usize,
+        height: usize,
+        stride: usize,
+    ) -> Self {
+        Self {
+            cells: Cells::Borrowed(cells),
+            width,
+            height,
+            stride,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The distance between the start of one row and the next
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// The flat buffer, including any line endings between the rows
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// The position of `(x, y)` in [`cells`](Grid::cells)
+    pub fn index(&self, x: usize, y: usize) -> usize {
+        y * self.stride + x
+    }
+
+    /// The `(x, y)` of a position in [`cells`](Grid::cells)
+    pub fn coords(&self, index: usize) -> (usize, usize) {
+        (index % self.stride, index / self.stride)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[self.index(x, y)])
+    }
+
+    /// The indices of the cells above, below, left and right of `index` that are in the grid
+    ///
+    /// ```
+    /// let (_, grid) = parsers::byte_grid("abc\ndef\n").unwrap();
+    /// let neighbours: Vec<_> = grid.neighbours4(0).map(|i| grid.cells()[i]).collect();
+    /// assert_eq!(neighbours, b"bd");
+    /// ```
+    pub fn neighbours4(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        self.neighbours(index, &NEIGHBOURS4)
+    }
+
+    /// Like [`neighbours4`](Grid::neighbours4), including the diagonals
+    ///
+    /// ```
+    /// let (_, grid) = parsers::byte_grid("abc\ndef\n").unwrap();
+    /// let neighbours: Vec<_> = grid.neighbours8(1).map(|i| grid.cells()[i]).collect();
+    /// assert_eq!(neighbours, b"cfeda");
+    /// ```
+    pub fn neighbours8(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        self.neighbours(index, &NEIGHBOURS8)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        index: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = usize> + 'a {
+        let (x, y) = self.coords(index);
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx)?;
+            let y = y.checked_add_signed(dy)?;
+            self.contains(x, y).then(|| self.index(x, y))
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        let start = self.index(0, y);
+        &self.cells[start..start + self.width]
+    }
+
+    /// ```
+    /// let (_, grid) = parsers::byte_grid("abc\ndef").unwrap();
+    /// assert!(grid.rows().eq([b"abc", b"def"]));
+    /// ```
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        (0..self.height).map(move |y| &self.cells[self.index(x, y)])
+    }
+
+    /// ```
+    /// let (_, grid) = parsers::byte_grid("abc\ndef").unwrap();
+    /// let columns: Vec<Vec<u8>> = grid.columns().map(|c| c.copied().collect()).collect();
+    /// assert_eq!(columns, [b"ad", b"be", b"cf"]);
+    /// ```
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(self.contains(x, y), "({x}, {y}) is outside the grid");
+        &self.cells[self.index(x, y)]
+    }
+}
+
+/// Parses the lines of a grid of characters without copying them. All the lines must be
+/// the same length, and the grid ends at a blank line or the end of the input.
+/// The line ending after the last row is consumed
+///
+/// ```
+/// let (rest, grid) = parsers::byte_grid("#.#\n.#.\n\nRLR").unwrap();
+/// assert_eq!((rest, grid.width(), grid.height()), ("\nRLR", 3, 2));
+/// assert!(parsers::byte_grid("#.#\n.#\n").is_err());
+/// ```
+pub fn byte_grid(input: &'static str) -> IResult<&'static str, Grid<u8>> {
+    // the grid only ends after a line ending, so `rest` is on a char boundary
+    let offset = |rest: &[u8]| input.len() - rest.len();
+    match crate::bytes::byte_grid(input.as_bytes()) {
+        Ok((rest, grid)) => Ok((&input[offset(rest)..], grid)),
+        Err(err) => Err(err.map(|err| Error::new(&input[offset(err.input)..], err.code))),
+    }
+}
+
+/// Parses each line of a grid into cells using `f`, collecting them into one buffer.
+/// Like [`byte_grid`], every row must have the same number of cells
+///
+/// ```
+/// use nom::Parser;
+/// let digit = nom::character::complete::satisfy(|c| c.is_ascii_digit())
+///     .map(|c| c.to_digit(10).unwrap());
+/// let result: nom::IResult<_, parsers::Grid<u32>> = parsers::cell_grid(digit).parse("123\n456\n");
+/// let (_, grid) = result.unwrap();
+/// assert_eq!(grid.stride(), 3);
+/// assert_eq!(grid.row(1), [4, 5, 6]);
+/// ```
+pub fn cell_grid<'a, O, E, F>(mut f: F) -> impl Parser<&'a str, Grid<O>, E>
+where
+    O: 'static,
+    F: Parser<&'a str, O, E>,
+    E: ParseError<&'a str>,
+{
+    move |mut input: &'a str| {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+        loop {
+            let row_start = input;
+            let start = cells.len();
+            loop {
+                match f.parse(input) {
+                    Ok((rest, _)) if rest.len() == input.len() => {
+                        return Err(Err::Error(E::from_error_kind(input, ErrorKind::Many1)))
+                    }
+                    Ok((rest, cell)) => {
+                        input = rest;
+                        cells.push(cell);
+                    }
+                    Err(Err::Error(_)) => break,
+                    Err(err) => return Err(err),
+                }
+            }
+
+            match cells.len() - start {
+                0 if height == 0 => {
+                    return Err(Err::Error(E::from_error_kind(input, ErrorKind::Many1)))
+                }
+                0 => break,
+                len if height == 0 => width = len,
+                len if len != width => {
+                    return Err(Err::Error(E::from_error_kind(row_start, ErrorKind::Verify)))
+                }
+                _ => {}
+            }
+            height += 1;
+
+            match line_ending::<_, E>(input) {
+                Ok((rest, _)) => input = rest,
+                Err(_) => break,
+            }
+        }
+
+        let grid = Grid {
+            cells: Cells::Owned(cells),
+            width,
+            height,
+            stride: width,
+        };
+        Ok((input, grid))
+    }
+}
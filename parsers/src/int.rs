@@ -0,0 +1,138 @@
+use std::{
+    num::{IntErrorKind, ParseIntError},
+    str::FromStr,
+};
+
+use nom::{
+    error::{Error, ErrorKind},
+    Err, IResult,
+};
+
+/// The integer types, which can be parsed in any radix
+pub trait Integer: Sized {
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError>;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+                    <$t>::from_str_radix(src, radix)
+                }
+            }
+        )*
+    };
+}
+
+integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+fn check_radix(radix: u32) {
+    assert!(
+        (2..=36).contains(&radix),
+        "radix must be between 2 and 36, got {radix}"
+    );
+}
+
+/// Takes the digits of a number, with a leading `+` or `-` if `signed`
+fn digits(input: &str, radix: u32, signed: bool) -> IResult<&str, &str> {
+    let sign = usize::from(signed && input.starts_with(['+', '-']));
+    let len = input[sign..]
+        .find(|c: char| !c.is_digit(radix))
+        .unwrap_or(input.len() - sign);
+    if len == 0 {
+        return Err(Err::Error(Error::new(input, ErrorKind::Digit)));
+    }
+    let (digits, rest) = input.split_at(sign + len);
+    Ok((rest, digits))
+}
+
+/// ```
+/// assert_eq!(parsers::signed_number::<i32>("-3 4"), Ok((" 4", -3)));
+/// assert_eq!(parsers::signed_number::<i32>("+3"), Ok(("", 3)));
+/// assert!(parsers::signed_number::<u32>("-3").is_err());
+/// ```
+pub fn signed_number<O>(input: &str) -> IResult<&str, O>
+where
+    O: FromStr,
+{
+    let (rest, digits) = digits(input, 10, true)?;
+    match digits.parse() {
+        Ok(n) => Ok((rest, n)),
+        Err(_) => Err(Err::Error(Error::new(input, ErrorKind::MapRes))),
+    }
+}
+
+/// Parses a number written in `radix`, without a prefix such as `0x`.
+/// Panics if `radix` is not in `2..=36`, like [`u32::from_str_radix`]
+///
+/// ```
+/// assert_eq!(parsers::number_radix::<u8>(16)("ff,"), Ok((",", 255)));
+/// assert_eq!(parsers::number_radix::<u32>(2)("1012"), Ok(("2", 5)));
+/// assert!(std::panic::catch_unwind(|| parsers::number_radix::<u32>(37)).is_err());
+/// ```
+pub fn number_radix<O>(radix: u32) -> impl Fn(&str) -> IResult<&str, O>
+where
+    O: Integer,
+{
+    check_radix(radix);
+    move |input| {
+        let (rest, digits) = digits(input, radix, false)?;
+        match O::from_str_radix(digits, radix) {
+            Ok(n) => Ok((rest, n)),
+            Err(_) => Err(Err::Error(Error::new(input, ErrorKind::MapRes))),
+        }
+    }
+}
+
+/// Like [`number`](crate::number), but a number that does not fit in `O` is a
+/// [`Failure`](Err::Failure) with [`ErrorKind::TooLarge`] rather than a recoverable error
+///
+/// ```
+/// use nom::{error::{Error, ErrorKind}, Err};
+/// assert_eq!(parsers::checked_number::<u8>("255"), Ok(("", 255)));
+/// assert_eq!(
+///     parsers::checked_number::<u8>("256"),
+///     Err(Err::Failure(Error::new("256", ErrorKind::TooLarge)))
+/// );
+/// ```
+pub fn checked_number<O>(input: &str) -> IResult<&str, O>
+where
+    O: Integer,
+{
+    checked(input, 10, false)
+}
+
+/// Like [`signed_number`], reporting numbers that do not fit in `O` as in [`checked_number`]
+pub fn checked_signed_number<O>(input: &str) -> IResult<&str, O>
+where
+    O: Integer,
+{
+    checked(input, 10, true)
+}
+
+/// Like [`number_radix`], reporting numbers that do not fit in `O` as in [`checked_number`]
+pub fn checked_number_radix<O>(radix: u32) -> impl Fn(&str) -> IResult<&str, O>
+where
+    O: Integer,
+{
+    check_radix(radix);
+    move |input| checked(input, radix, false)
+}
+
+fn checked<O: Integer>(input: &str, radix: u32, signed: bool) -> IResult<&str, O> {
+    let (rest, digits) = digits(input, radix, signed)?;
+    match O::from_str_radix(digits, radix) {
+        Ok(n) => Ok((rest, n)),
+        Err(err) => {
+            let overflow = matches!(
+                err.kind(),
+                IntErrorKind::PosOverflow | IntErrorKind::NegOverflow
+            );
+            Err(match overflow {
+                true => Err::Failure(Error::new(input, ErrorKind::TooLarge)),
+                false => Err::Error(Error::new(input, ErrorKind::MapRes)),
+            })
+        }
+    }
+}
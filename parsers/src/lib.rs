@@ -3,15 +3,27 @@
 use std::str::FromStr;
 
 use nom::{
-    bytes::complete::is_a,
     character::complete::{digit1, line_ending},
     error::{ErrorKind, ParseError},
     Err, IResult, InputIter, InputLength, InputTake, Parser,
 };
 
+pub mod bytes;
+pub mod diagnostic;
 mod ext;
 pub use ext::*;
 pub mod gen;
+mod grid;
+pub use grid::*;
+mod int;
+pub use int::*;
+mod parse;
+pub use parse::*;
+mod template;
+pub use template::*;
+
+#[doc(hidden)]
+pub use nom as __nom;
 
 /// ```
 /// let line = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
@@ -38,9 +50,7 @@ where
 }
 
 pub fn binary(input: &str) -> IResult<&str, usize> {
-    is_a("01")
-        .map_res(|s| usize::from_str_radix(s, 2))
-        .parse(input)
+    number_radix(2)(input)
 }
 
 pub fn lines<'a, O, E, F>(f: F) -> impl Parser<&'a str, Vec<O>, E>
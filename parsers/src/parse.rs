@@ -0,0 +1,57 @@
+use nom::IResult;
+
+/// A type with a parser for its text form. `Type::parse` can be used anywhere
+/// a nom parser can, so it works with the [`ParserExt`](crate::ParserExt) combinators
+pub trait AocParse: Sized {
+    fn parse(input: &str) -> IResult<&str, Self>;
+}
+
+/// Derives [`AocParse`] for structs and enums.
+///
+/// Each field is parsed with [`number`](crate::number) unless it has
+/// `#[with(parser)]`. The literal text around the fields is matched with
+///
+/// * `#[prefix("text")]` on the type, or on a field, to match text before it
+/// * `#[separator("text")]` on the type, to match text between each field
+/// * `#[tag("text")]` on enum variants, to match the text that starts them.
+///   The variants are tried in order
+///
+/// ```
+/// use nom::{bytes::complete::tag, Parser};
+/// use parsers::{AocParse, ParserExt};
+///
+/// #[derive(AocParse, Debug, PartialEq)]
+/// #[separator("-")]
+/// struct Range {
+///     start: u32,
+///     end: u32,
+/// }
+///
+/// #[derive(AocParse, Debug, PartialEq)]
+/// enum Operation {
+///     #[tag("old * old")]
+///     Square,
+///     #[tag("old * ")]
+///     Mul(u64),
+///     #[tag("old + ")]
+///     Add(u64),
+/// }
+///
+/// #[derive(AocParse, Debug, PartialEq)]
+/// struct Monkey {
+///     #[prefix("Monkey ")]
+///     id: usize,
+///     #[prefix(": new = ")]
+///     #[with(Operation::parse)]
+///     op: Operation,
+/// }
+///
+/// let pairs = Range::parse.separated_array(tag(",")).parse("2-4,6-8");
+/// assert_eq!(pairs, Ok(("", [Range { start: 2, end: 4 }, Range { start: 6, end: 8 }])));
+///
+/// let monkey = Monkey::parse("Monkey 0: new = old * old");
+/// assert_eq!(monkey, Ok(("", Monkey { id: 0, op: Operation::Square })));
+/// assert_eq!(Operation::parse("old + 3"), Ok(("", Operation::Add(3))));
+/// assert!(Operation::parse("old - 3").is_err());
+/// ```
+pub use parsers_macros::AocParse;
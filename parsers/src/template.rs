@@ -0,0 +1,63 @@
+use nom::{
+    character::complete::{alphanumeric1, anychar},
+    IResult,
+};
+
+use crate::{checked_number, checked_signed_number};
+
+/// Builds a parser from a template of literal text with `{Type}` placeholders,
+/// returning the fields as a tuple (or the field itself if there is only one).
+/// `{{` and `}}` match a literal brace.
+///
+/// Each field is parsed with [`Field`], and the text between them must match exactly,
+/// so a failure points at the first literal or field that did not match
+///
+/// ```
+/// use parsers::{template, diagnostic::Diagnostic};
+///
+/// let sensor = template!("Sensor at x={i32}, y={i32}: closest beacon is at x={i32}, y={i32}");
+/// let line = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
+/// assert_eq!(sensor(line), Ok(("", (2, 18, -2, 15))));
+///
+/// let line = "Sensor at x=2; y=18: closest beacon is at x=-2, y=15";
+/// let err = sensor(line).unwrap_err();
+/// assert_eq!(Diagnostic::from_nom(line, err).column, 14);
+///
+/// let valve = template!("Valve {&str} has flow rate={u32}");
+/// assert_eq!(valve("Valve AA has flow rate=0"), Ok(("", ("AA", 0))));
+/// ```
+pub use parsers_macros::template;
+
+/// A value that can fill a `{Type}` placeholder in a [`template!`]
+pub trait Field<'a>: Sized {
+    fn field(input: &'a str) -> IResult<&'a str, Self>;
+}
+
+macro_rules! field {
+    ($parser:ident: $($t:ty),*) => {
+        $(
+            /// Numbers that do not fit are a failure
+            impl Field<'_> for $t {
+                fn field(input: &str) -> IResult<&str, Self> {
+                    $parser(input)
+                }
+            }
+        )*
+    };
+}
+
+field!(checked_number: u8, u16, u32, u64, u128, usize);
+field!(checked_signed_number: i8, i16, i32, i64, i128, isize);
+
+impl Field<'_> for char {
+    fn field(input: &str) -> IResult<&str, Self> {
+        anychar(input)
+    }
+}
+
+/// A word made of letters and digits
+impl<'a> Field<'a> for &'a str {
+    fn field(input: &'a str) -> IResult<&'a str, Self> {
+        alphanumeric1(input)
+    }
+}